@@ -0,0 +1,98 @@
+use crate::{error::{parse_number_in, ParseError}, generate::Rng, solution::{Params, Solution}};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(input)
+    }
+}
+
+/// The most calories a single snack can have, the totals of the elves are added up in an `i64` so that they always fit
+const MAX_CALORIES: i32 = 1_000_000;
+
+fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    let elves = input
+        .split("\n\n")
+        .map(|x| x.split('\n').filter(|s| !s.is_empty()).map(|y| parse_number_in(input, y, 0..=MAX_CALORIES)).collect())
+        .collect::<Result<Vec<Vec<i32>>, ParseError>>()?;
+
+    if elves.len() < 3 {
+        return Err(ParseError::end_of_input(input, "expected at least 3 elves"));
+    }
+
+    Ok(elves)
+}
+
+
+fn part1(parsed: &[Vec<i32>]) -> String {
+
+    let elf_calories: Vec<i64> = parsed.iter().map(|elf| elf.iter().map(|&c| c as i64).sum()).collect();
+
+    let max = elf_calories.iter().max().unwrap();
+
+    max.to_string()
+}
+
+fn part2(parsed: &[Vec<i32>]) -> String {
+
+    let mut elf_calories: Vec<i64> = parsed.iter().map(|elf| elf.iter().map(|&c| c as i64).sum()).collect();
+    
+    elf_calories.sort_unstable();
+
+    let top_3_total = &elf_calories[(elf_calories.len() - 3)..];
+    let result: i64 = top_3_total.iter().sum();
+
+    result.to_string()
+}
+
+/// `size` elves, carrying 1 to 5 snacks each
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(3))
+        .map(|_| (0..rng.range(1..=5)).map(|_| format!("{}\n", rng.range(1000..=20000))).collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input), "24000");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input), "45000");
+    }
+
+    #[test]
+    fn large_totals_dont_overflow() {
+        let input = parse(&format!("{}\n1\n\n2", "1000000\n".repeat(3000))).unwrap();
+
+        assert_eq!(part1(&input), "3000000000");
+        assert_eq!(part2(&input), "3000000003");
+
+        let err = parse("1000\n2147483647\n\n1\n\n2").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "2147483647"));
+        assert_eq!(err.message, "expected a number from 0 to 1000000");
+    }
+}
@@ -1,4 +1,4 @@
-use std::fs;
+use crate::{error::ParseError, generate::Rng, solution::{Params, Solution}};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 enum Shape {
@@ -51,7 +51,7 @@ fn get_state_shape(enemy: &Shape, desired_state: &WinState) -> Shape {
 
     let all_states: Vec<(Shape, WinState)> = options
         .into_iter()
-        .map(|x| (x, get_winner(&(enemy.clone(), x.clone()))))
+        .map(|x| (x, get_winner(&(*enemy, x))))
         .collect();
 
     all_states.into_iter().find(|(_, w)| w == desired_state).unwrap().0
@@ -78,7 +78,7 @@ fn get_winner(round: &Round) -> WinState {
 }
 
 fn calculate_points(round: &Round) -> (i32, i32) {
-    let winner = get_winner(&round);
+    let winner = get_winner(round);
 
     let left_points = get_shape_points(&round.0);
     let right_points = get_shape_points(&round.1);
@@ -90,29 +90,50 @@ fn calculate_points(round: &Round) -> (i32, i32) {
     }
 }
 
-fn main() {
-    let contents = fs::read_to_string("./input")
-        .expect("File not found");
-
-    let parsed: Vec<(String, String)> = contents
-        .split("\n")
-        .filter(|x| x.len() > 0)
-        .map(|xs| xs
-             .split(" ")
-             .collect::<Vec<&str>>()
-         )
-        .filter(|xs| xs.len() == 2)
-        .map(|x| (x[0].to_string(), x[1].to_string()))
-        .collect();
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<(String, String)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
 
-    println!("Part 1: {}", part1(&parsed));
-    println!("Part 2: {}", part2(&parsed));
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(input)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<(String, String)>, ParseError> {
+    input
+        .split('\n')
+        .filter(|x| !x.is_empty())
+        .map(|xs| {
+            let (left, right) = xs
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(input, xs, "expected 2 columns separated by a space"))?;
+
+            if ! matches!(left, "A" | "B" | "C") {
+                return Err(ParseError::at(input, left, "expected A, B or C"));
+            }
+
+            if ! matches!(right, "X" | "Y" | "Z") {
+                return Err(ParseError::at(input, right, "expected X, Y or Z"));
+            }
+
+            Ok((left.to_string(), right.to_string()))
+        })
+        .collect()
 }
 
 
-fn part1(input: &Vec<(String, String)>) -> String {
+fn part1(input: &[(String, String)]) -> String {
     let result: i32 = input
-        .into_iter()
+        .iter()
         .map(|t| (parse_shape(&t.0).unwrap(), parse_shape(&t.1).unwrap()))
         .map(|x| calculate_points(&x).1)
         .sum();
@@ -120,12 +141,40 @@ fn part1(input: &Vec<(String, String)>) -> String {
     result.to_string()
 }
 
-fn part2(input: &Vec<(String, String)>) -> String {
+fn part2(input: &[(String, String)]) -> String {
     let result: i32 = input
-        .into_iter()
+        .iter()
         .map(|t| (parse_shape(&t.0).unwrap(), parse_winstate(&t.1).unwrap()))
         .map(|(s, d)| calculate_points(&(s, get_state_shape(&s, &d))).1)
         .sum();
 
     result.to_string()
 }
+
+/// `size` rounds of the strategy guide
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{} {}\n", rng.pick(&["A", "B", "C"]), rng.pick(&["X", "Y", "Z"])))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input), "15");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input), "12");
+    }
+}
@@ -0,0 +1,151 @@
+use crate::{error::ParseError, generate::Rng, solution::{Params, Solution}};
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+fn get_priority(c: &char) -> i32 {
+    if c.is_ascii_uppercase() {
+        return (*c as i32) - ('A' as i32) + 27;
+    }
+
+    (*c as i32) - ('a' as i32) + 1
+}
+
+fn find_common(strs: Vec<&[char]>) -> Option<char> {
+    if strs.len() < 2 {
+        return None;
+    }
+
+    let first = strs.first().unwrap();
+    let sets: Vec<HashSet<char>> = strs.iter().skip(1).map(|s| HashSet::from_iter(s.iter().cloned())).collect();
+
+    let common = first.iter().find(|c| sets.iter().all(|s| s.contains(c)));
+        
+    common.copied()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(input)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let lines: Vec<&str> = input.split('\n').filter(|x| !x.is_empty()).collect();
+
+    let rucksacks = lines
+        .iter()
+        .map(|x| {
+            if let Some((i, c)) = x.char_indices().find(|(_, c)| ! c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(input, &x[i..(i + c.len_utf8())], "items have to be letters"));
+            }
+
+            if x.len() % 2 != 0 {
+                return Err(ParseError::at(input, x, "a rucksack has to hold an even number of items"));
+            }
+
+            let xs: Vec<char> = x.chars().collect();
+
+            if find_common(vec![&xs[0..(xs.len() / 2)], &xs[(xs.len() / 2)..]]).is_none() {
+                return Err(ParseError::at(input, x, "the compartments of a rucksack have no item in common"));
+            }
+
+            Ok(xs)
+        })
+        .collect::<Result<Vec<Vec<char>>, ParseError>>()?;
+
+    if rucksacks.len() % 3 != 0 {
+        return Err(ParseError::end_of_input(input, "the rucksacks have to come in groups of 3"));
+    }
+
+    if let Some(i) = rucksacks.chunks(3).position(|x| find_common(x.iter().map(|x| x.as_slice()).collect()).is_none()) {
+        return Err(ParseError::at(input, lines[i * 3], "a group of 3 rucksacks has no badge in common"));
+    }
+
+    Ok(rucksacks)
+}
+
+fn part1(input: &[Vec<char>]) -> String {
+    let result: i32 = input.iter()
+        .map(|xs| find_common(vec![&xs[0..(xs.len() / 2)], &xs[(xs.len() / 2)..]]))
+        .map(|c| get_priority(&c.unwrap()))
+        .sum();
+
+    result.to_string()
+}
+
+fn part2(input: &[Vec<char>]) -> String {
+    let result: i32 = input
+        .chunks(3)
+        .map(|x| find_common(x.iter().map(|x| x.as_slice()).collect()))
+        .map(|c| get_priority(&c.unwrap()))
+        .sum();
+
+    result.to_string()
+}
+
+/// Groups of 3 rucksacks, each with a single item in both of its compartments and a single badge shared by the group
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut output = String::new();
+
+    for _ in 0..size.div_ceil(3).max(1) {
+        let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+        rng.shuffle(&mut items);
+
+        // Every elf of the group picks from their own 17 items, so that only the badge is shared
+        let (badge, pools) = (items[0], items[1..].chunks(17));
+
+        for pool in pools {
+            let (common, others) = pool.split_first().unwrap();
+            let (left_items, right_items) = others.split_at(8);
+            let half = rng.range(2..=16) as usize;
+
+            let mut left = vec![*common, badge];
+            left.extend((2..half).map(|_| *rng.pick(left_items)));
+
+            let mut right = vec![*common];
+            right.extend((1..half).map(|_| *rng.pick(right_items)));
+
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+
+            output.extend(left);
+            output.extend(right);
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input), "157");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input), "70");
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{error::{parse_number, ParseError}, generate::Rng, solution::{Params, Solution}};
+
+
+type SectionRange = (i32, i32);
+
+type ElfPair = (SectionRange, SectionRange);
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<ElfPair>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(input)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<ElfPair>, ParseError> {
+    input
+        .split('\n')
+        .filter(|x| !x.is_empty())
+        .map(|xs| {
+            let (left, right) = xs
+                .split_once(',')
+                .ok_or_else(|| ParseError::at(input, xs, "expected 2 section ranges separated by a comma"))?;
+
+            Ok((parse_range(input, left)?, parse_range(input, right)?))
+        })
+        .collect()
+}
+
+fn parse_range(input: &str, raw: &str) -> Result<SectionRange, ParseError> {
+    let (start, end) = raw
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(input, raw, "expected a section range like 2-4"))?;
+
+    Ok((parse_number(input, start)?, parse_number(input, end)?))
+}
+
+fn fully_overlap(pair: &ElfPair) -> bool {
+    let ((a1, b1), (a2, b2)) = pair;
+
+    (a1 <= a2 && b1 >= b2)
+    || (a1 >= a2 && b1 <= b2)
+}
+
+fn overlap(pair: &ElfPair) -> bool {
+    let ((a1, b1), (a2, b2)) = pair;
+
+    ! ((b1 < a2) || (a1 > b2))
+}
+
+fn part1(input: &[ElfPair]) -> String {
+    let result = input.iter()
+        .filter(|x| fully_overlap(x))
+        .collect::<Vec<&ElfPair>>()
+        .len();
+
+    result.to_string()
+}
+
+fn part2(input: &[ElfPair]) -> String {
+    let result = input.iter()
+        .filter(|x| overlap(x))
+        .collect::<Vec<&ElfPair>>()
+        .len();
+
+    result.to_string()
+}
+
+/// `size` pairs of section ranges between 1 and 99
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut range = || {
+        let start = rng.range(1..=99);
+        format!("{}-{}", start, rng.range(start..=99))
+    };
+
+    (0..size).map(|_| format!("{},{}\n", range(), range())).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input), "2");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input), "4");
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{error::{parse_number, ParseError}, generate::Rng, solution::{Params, Solution}};
+
+#[derive(Debug)]
+pub struct Instruction {
+    amount: i32,
+    source: i32,
+    destination: i32,
+}
+
+type Stack<T> = Vec<T>;
+
+fn parse_stacks(input: &str, raw: &str) -> Result<Vec<Stack<char>>, ParseError> {
+    let raw_stacks = raw.split('\n').collect::<Vec<&str>>();
+    let (identifiers, rows) = raw_stacks.split_last().unwrap_or((&"", &[]));
+    let stacks_count = identifiers.split_whitespace().count();
+
+    let mut stacks = vec![Vec::new(); stacks_count];
+
+    for row in rows.iter().rev() {
+        // Crates look like `[X] `, so their letters are every 4th character starting from the 2nd one
+        for (i, (idx, c)) in row.char_indices().skip(1).step_by(4).enumerate() {
+            if c.is_whitespace() {
+                continue;
+            }
+
+            let stack = stacks
+                .get_mut(i)
+                .ok_or_else(|| ParseError::at(input, &row[idx..(idx + c.len_utf8())], "crate is outside of the numbered stacks"))?;
+
+            stack.push(c);
+        }
+    }
+
+    Ok(stacks)
+}
+
+fn parse_insts(input: &str, raw: &str, stacks_count: usize) -> Result<Vec<Instruction>, ParseError> {
+    let parse_stack = |raw: &str| {
+        let stack: i32 = parse_number(input, raw)?;
+
+        if stack < 1 || stack as usize > stacks_count {
+            return Err(ParseError::at(input, raw, format!("there are only {} stacks", stacks_count)));
+        }
+
+        Ok(stack)
+    };
+
+    raw.split('\n').filter(|x| !x.is_empty()).map(|x| {
+        let split = x.split_whitespace().collect::<Vec<&str>>();
+
+        let ["move", amount, "from", source, "to", destination] = split[..] else {
+            return Err(ParseError::at(input, x, "expected an instruction like `move 1 from 2 to 1`"));
+        };
+
+        Ok(Instruction {
+            amount: parse_number(input, amount)?,
+            source: parse_stack(source)?,
+            destination: parse_stack(destination)?,
+        })
+    }).collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = (Vec<Stack<char>>, Vec<Instruction>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(&input.0, &input.1)
+    }
+}
+
+fn parse(input: &str) -> Result<(Vec<Stack<char>>, Vec<Instruction>), ParseError> {
+    let (raw_stacks, raw_insts) = input
+        .split_once("\n\n")
+        .ok_or_else(|| ParseError::end_of_input(input, "expected a blank line between the stacks and the instructions"))?;
+
+    let stacks = parse_stacks(input, raw_stacks)?;
+    let insts = parse_insts(input, raw_insts, stacks.len())?;
+
+    Ok((stacks, insts))
+}
+
+
+fn part1(stacks: &[Stack<char>], insts: &[Instruction]) -> String {
+    let mut cloned_stacks = stacks.to_vec();
+
+    for inst in insts.iter() {
+        for _ in 0..inst.amount {
+            let popped = cloned_stacks[(inst.source - 1) as usize].pop();
+
+            if popped.is_none() {
+                break;
+            }
+
+            cloned_stacks[(inst.destination - 1) as usize].push(popped.unwrap());
+        }
+    }
+
+    // Empty stacks show up as spaces, so that the letters still line up with the stacks
+    cloned_stacks.iter().map(|x| x.last().copied().unwrap_or(' ')).collect::<String>()
+}
+
+fn part2(stacks: &[Stack<char>], insts: &[Instruction]) -> String {
+    let mut cloned_stacks = stacks.to_vec();
+
+    for inst in insts.iter() {
+        let mut temp = Vec::new();
+        for _ in 0..inst.amount {
+            let popped = cloned_stacks[(inst.source - 1) as usize].pop();
+            
+            if popped.is_none() {
+                break;
+            }
+
+            temp.push(popped.unwrap());
+        }
+
+        cloned_stacks[(inst.destination - 1) as usize].extend(temp.iter().rev());
+    }
+
+    // Empty stacks show up as spaces, so that the letters still line up with the stacks
+    cloned_stacks.iter().map(|x| x.last().copied().unwrap_or(' ')).collect::<String>()
+}
+
+/// `size` stacks (up to 99) and 50 moves per stack, no move empties a stack so that every stack has a crate on top at the end
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(1, 99);
+    let stacks: Vec<Vec<char>> = (0..count)
+        .map(|_| (0..rng.range(1..=8)).map(|_| rng.letter('z').to_ascii_uppercase()).collect())
+        .collect();
+
+    let mut heights: Vec<i64> = stacks.iter().map(|stack| stack.len() as i64).collect();
+    let mut moves = String::new();
+
+    for _ in 0..(count * 50) {
+        let sources: Vec<usize> = (0..count).filter(|&i| heights[i] > 1).collect();
+
+        if sources.is_empty() || count == 1 {
+            break;
+        }
+
+        let source = *rng.pick(&sources);
+        let destination = (source + 1 + rng.below(count - 1)) % count;
+        let amount = rng.range(1..=(heights[source] - 1));
+
+        heights[source] -= amount;
+        heights[destination] += amount;
+        moves.push_str(&format!("move {} from {} to {}\n", amount, source + 1, destination + 1));
+    }
+
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+    let rows = (0..height).rev().map(|level| {
+        stacks.iter()
+            .map(|stack| stack.get(level).map_or(String::from("   "), |c| format!("[{}]", c)))
+            .collect::<Vec<String>>()
+            .join(" ")
+    });
+
+    let numbers = (1..=count).map(|i| format!("{:^3}", i)).collect::<Vec<String>>().join(" ");
+
+    format!("{}\n{}\n\n{}", rows.collect::<Vec<String>>().join("\n"), numbers, moves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input.0, &input.1), "CMZ");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input.0, &input.1), "MCD");
+    }
+}
@@ -0,0 +1,152 @@
+use std::{collections::HashMap, cmp::max};
+
+use crate::{error::ParseError, generate::Rng, solution::{Params, Solution}};
+
+
+fn parse(input: &str) -> Vec<char> {
+    input.chars().collect::<Vec<char>>()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse(input.trim()))
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(input)
+    }
+}
+
+fn find_unique_chars_idx(data: &[char], n: u32) -> Option<u32> {
+    let mut last_seen: HashMap<char, u32> = HashMap::new();
+
+    let mut window_start = 0u32; // The window of unique chars starts after the last repeated one
+
+    for (i, c) in data.iter().enumerate() {
+        let curr_last_seen = last_seen.get(c);
+
+        if let Some(lp) = curr_last_seen {
+            window_start = max(window_start, *lp + 1);
+        }
+
+        last_seen.insert(*c, i as u32);
+
+        if i as u32 + 1 - window_start >= n {
+            return Some((i + 1) as u32);
+        }
+    }
+
+    None
+}
+
+fn part1(data: &[char]) -> String {
+    let result = find_unique_chars_idx(data, 4);
+
+    if let Some(idx) = result { idx.to_string() } else { String::from("Not found") }
+}
+
+fn part2(data: &[char]) -> String {
+    let result = find_unique_chars_idx(data, 14);
+
+    if let Some(idx) = result { idx.to_string() } else { String::from("Not found") }
+}
+
+/// A datastream of `size` characters (at least 30). It starts with repeats of 3 letters, then of 13,
+/// so that the markers can't show up before the 14 different letters that follow
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(30);
+    let mut letters: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut letters);
+
+    let few = rng.below(size / 2);
+    let many = rng.below(size / 2 - 14 + 1);
+
+    let mut stream: Vec<char> = (0..few).map(|_| *rng.pick(&letters[..3])).collect();
+    stream.extend((0..many).map(|_| *rng.pick(&letters[..13])));
+    stream.extend(&letters[..14]);
+    stream.extend((stream.len()..size).map(|_| rng.letter('z')));
+
+    stream.into_iter().chain(['\n']).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    fn chars(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(part1(&parse(EXAMPLE.trim())), "11");
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(part2(&parse(EXAMPLE.trim())), "26");
+    }
+
+    #[test]
+    fn find_unique_chars_idx_matches_the_examples() {
+        // (buffer, start of packet, start of message)
+        let examples = [
+            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+        ];
+
+        for (buffer, packet, message) in examples {
+            assert_eq!(find_unique_chars_idx(&chars(buffer), 4), Some(packet), "{}", buffer);
+            assert_eq!(find_unique_chars_idx(&chars(buffer), 14), Some(message), "{}", buffer);
+        }
+    }
+
+    #[test]
+    fn find_unique_chars_idx_counts_the_whole_window() {
+        assert_eq!(find_unique_chars_idx(&chars("abcd"), 4), Some(4));
+        assert_eq!(find_unique_chars_idx(&chars("aabcd"), 4), Some(5));
+    }
+
+    #[test]
+    fn find_unique_chars_idx_without_a_marker() {
+        assert_eq!(find_unique_chars_idx(&chars("abcabcabc"), 4), None);
+        assert_eq!(find_unique_chars_idx(&chars("abc"), 4), None);
+        assert_eq!(find_unique_chars_idx(&[], 4), None);
+    }
+
+    /// Checks every window from the start, one by one
+    fn find_unique_chars_idx_brute_force(data: &[char], n: u32) -> Option<u32> {
+        let n = n as usize;
+
+        (n..=data.len())
+            .find(|&end| data[(end - n)..end].iter().collect::<HashSet<_>>().len() == n)
+            .map(|end| end as u32)
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(5000))]
+
+        // A small alphabet, so that most of the windows have a repeated character
+        #[test]
+        fn find_unique_chars_idx_matches_brute_force(data in vec(prop::char::range('a', 'f'), 0..40), n in 1..=6u32) {
+            prop_assert_eq!(find_unique_chars_idx(&data, n), find_unique_chars_idx_brute_force(&data, n));
+        }
+    }
+}
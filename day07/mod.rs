@@ -0,0 +1,291 @@
+use std::mem;
+
+use crate::{error::{parse_number_in, ParseError}, generate::Rng, solution::{Params, Solution}};
+
+/**
+ * This shit was a disaster. Implementing a 2 way tree in rust seems to be impossible,
+ * and it took me like 5 hours to implement a basic zipper as an alternative.
+ */
+
+#[derive(Debug)]
+#[allow(dead_code)] // File names are only kept around for debugging
+enum Node {
+    File(String, usize),
+    Dir(String, Vec<Node>)
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Cd(String),
+    Ls,
+    Dir(String),
+    File(String, usize),
+}
+
+/// No file is larger than the disk, so the sizes of the directories can't overflow
+const DISK_SIZE: usize = 70_000_000;
+
+#[derive(Debug)]
+struct FileZipper {
+    node: Node,
+    crumbs: Vec<Node>,
+}
+
+impl FileZipper {
+    fn add_node(&mut self, node: Node) {
+        if let Node::Dir(_, children) = &mut self.node {
+            children.push(node);
+        }
+    }
+
+    fn change_directory(&mut self, name: &str) {
+        if name == ".." {
+            let parent = self.crumbs.pop();
+            if let Some(node) = parent {
+                let curr = mem::replace(&mut self.node, node);
+
+                if let Node::Dir(_, children) = &mut self.node {
+                    children.push(curr);
+                }
+            }
+        } else {
+            if let Node::Dir(curr_name, children) = &mut self.node {
+                let idx = children.iter().position(|x| {
+                    match x {
+                        Node::Dir(n, _) => n.eq(name),
+                        _ => false,
+                    }
+                });
+
+                if idx.is_none() {
+                    return;
+                }
+
+                let item = children.swap_remove(idx.unwrap());
+                let mut new_children = Vec::<Node>::new();
+                while let Some(element) = children.pop() {
+                    new_children.push(element);
+                }
+                self.crumbs.push(Node::Dir(curr_name.clone(), new_children));
+                self.node = item;
+            }
+        }
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+
+    let parse_command = |x: &str| {
+        if let Some(cmd) = x.strip_prefix("$ ") {
+            if let Some(dir) = cmd.strip_prefix("cd ") {
+                return Ok(Command::Cd(String::from(dir.trim())));
+            }
+
+            if cmd.trim() == "ls" {
+                return Ok(Command::Ls);
+            }
+
+            return Err(ParseError::at(input, cmd, "unknown command, expected cd or ls"));
+        }
+
+        if let Some(name) = x.strip_prefix("dir ") {
+            return Ok(Command::Dir(String::from(name)));
+        }
+
+        match x.split_once(' ') {
+            Some((size, name)) if size.starts_with(|c: char| c.is_ascii_digit()) => {
+                Ok(Command::File(String::from(name), parse_number_in(input, size, 0..=DISK_SIZE)?))
+            },
+            _ => Err(ParseError::at(input, x, "expected a command, a directory or a file")),
+        }
+    };
+
+    let commands = input.trim().lines().map(parse_command).collect::<Result<Vec<Command>, ParseError>>()?;
+
+    // Everything after the first command is relative to the root
+    match commands.first() {
+        Some(Command::Cd(dir)) if dir == "/" => Ok(commands),
+        Some(_) => Err(ParseError::at(input, input.trim().lines().next().unwrap_or(""), "the terminal output has to start with `$ cd /`")),
+        None => Err(ParseError::end_of_input(input, "the terminal output has to start with `$ cd /`")),
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(input)
+    }
+}
+
+fn get_folder_sizes(node: &Node, sizes: &mut Vec<usize>) -> usize {
+    match node {
+        Node::File(_, size) => *size,
+        Node::Dir(_, children) => {
+            let size = children.iter().map(|x| get_folder_sizes(x, sizes)).sum();
+            sizes.push(size);
+            size
+        },
+    }
+}
+
+fn create_filesystem(insts: &[Command]) -> FileZipper {
+    let root = Node::Dir(String::from("/"), Vec::new());
+
+    let mut zipper = FileZipper {
+        node: root,
+        crumbs: Vec::new(),
+    };
+
+    for inst in insts[1..].iter() {
+        match inst {
+            Command::Cd(dir) => zipper.change_directory(dir),
+            Command::Dir(name) => zipper.add_node(Node::Dir(String::from(name), Vec::new())),
+            Command::File(name, size) => zipper.add_node(Node::File(String::from(name), *size)),
+            _ => (),
+        }
+    }
+
+    while !zipper.crumbs.is_empty() {
+        zipper.change_directory("..");
+    }
+
+    zipper
+}
+
+fn part1(insts: &[Command]) -> String {
+    let zipper = create_filesystem(insts);
+
+    let mut sizes = Vec::new();
+
+    get_folder_sizes(&zipper.node, &mut sizes);
+
+    let result: usize = sizes.iter().filter(|&&x| x < 100000).sum();
+
+    result.to_string()
+}
+
+fn part2(insts: &[Command]) -> String {
+
+    let zipper = create_filesystem(insts);
+
+    let mut sizes = Vec::new();
+
+    get_folder_sizes(&zipper.node, &mut sizes);
+    sizes.sort();
+
+    let used = sizes.last().unwrap();
+    let free = DISK_SIZE.saturating_sub(*used);
+    let required = 30000000;
+
+    if free > required {
+        return 0.to_string();
+    }
+
+    let needed = required - free;
+    let folder_to_be_deleted = sizes.iter().find(|&&x| x > needed);
+
+    folder_to_be_deleted.unwrap().to_string()
+}
+
+/// The terminal output of exploring a random tree of `size` files and directories, which takes up
+/// between 40000000 and 69000000 of the disk so that part 2 has to delete something
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    struct Dir {
+        dirs: Vec<(String, usize)>,
+        files: Vec<(String, usize)>,
+    }
+
+    let mut dirs = vec![Dir { dirs: Vec::new(), files: Vec::new() }];
+    let mut weights = Vec::new();
+
+    for _ in 0..size {
+        let parent = rng.below(dirs.len());
+        let taken = |name: &String| dirs[parent].dirs.iter().any(|(n, _)| n == name) || dirs[parent].files.iter().any(|(n, _)| n == name);
+
+        let mut name: String = (0..rng.range(1..=8)).map(|_| rng.letter('z')).collect();
+        if rng.chance(50) {
+            name = format!("{}.{}", name, rng.pick(&["txt", "dat", "log", "ext", "lst"]));
+        }
+
+        if taken(&name) {
+            continue;
+        }
+
+        if rng.chance(25) {
+            let index = dirs.len();
+            dirs[parent].dirs.push((name, index));
+            dirs.push(Dir { dirs: Vec::new(), files: Vec::new() });
+        } else {
+            let weight = rng.range(1..=1000) << rng.below(10);
+            dirs[parent].files.push((name, weights.len()));
+            weights.push(weight);
+        }
+    }
+
+    // The weights of the files become their sizes, scaled to the size of the disk that's used
+    let used = rng.range(40000000..=69000000);
+    let total: i64 = weights.iter().sum::<i64>().max(1);
+    let sizes: Vec<u64> = weights.iter().map(|w| (w * used / total).max(1) as u64).collect();
+
+    fn explore(dirs: &[Dir], sizes: &[u64], dir: usize, output: &mut String) {
+        output.push_str("$ ls\n");
+
+        for (name, _) in &dirs[dir].dirs {
+            output.push_str(&format!("dir {}\n", name));
+        }
+
+        for (name, file) in &dirs[dir].files {
+            output.push_str(&format!("{} {}\n", sizes[*file], name));
+        }
+
+        for (name, child) in &dirs[dir].dirs {
+            output.push_str(&format!("$ cd {}\n", name));
+            explore(dirs, sizes, *child, output);
+            output.push_str("$ cd ..\n");
+        }
+    }
+
+    let mut output = String::from("$ cd /\n");
+    explore(&dirs, &sizes, 0, &mut output);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input), "95437");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input), "24933642");
+    }
+
+    #[test]
+    fn parse_rejects_files_larger_than_the_disk() {
+        let err = parse("$ cd /\n$ ls\n18446744073709551615 a\n").unwrap_err();
+
+        assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "18446744073709551615"));
+        assert_eq!(err.message, "expected a number from 0 to 70000000");
+    }
+}
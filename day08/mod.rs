@@ -0,0 +1,274 @@
+use std::cmp::max;
+
+use crate::{geometry::Point, grid::Grid, error::ParseError, generate::Rng, solution::{Params, Solution}};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+enum Direction {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+type DirVisionMap = Vec<Vec<u32>>;
+
+#[derive(Debug)]
+struct VisionMap {
+    top: Vec<Vec<u32>>,
+    bottom: Vec<Vec<u32>>,
+    left: Vec<Vec<u32>>,
+    right: Vec<Vec<u32>>,
+}
+
+#[derive(Debug)]
+struct VisionScores {
+    top: u32,
+    right: u32,
+    bottom: u32,
+    left: u32,
+}
+
+fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+
+    let rows = lines
+        .iter()
+        .map(|xs| xs
+             .char_indices()
+             .map(|(i, c)| c
+                  .to_digit(10)
+                  .ok_or_else(|| ParseError::at(input, &xs[i..(i + c.len_utf8())], "expected a digit")))
+             .collect::<Result<Vec<u32>, ParseError>>())
+        .collect::<Result<Vec<Vec<u32>>, ParseError>>()?;
+
+    Grid::from_rows(rows)
+        .map_err(|err| ParseError::at(input, lines[err.row], err.to_string()))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(input)
+    }
+}
+
+
+fn part1(grid: &Grid<u32>) -> String {
+    let vision_map = get_vision_map(grid, false);
+
+    let (xmax, ymax) = (grid.width() as i32, grid.height() as i32);
+    let mut visible = 0;
+
+    for y in 0..ymax {
+        for x in 0..xmax {
+            let scores = get_vision_scores(&vision_map, &Point::new(x, y), xmax, ymax);
+
+            if scores.left == x as u32
+               || scores.top == y as u32
+               || scores.right == ((xmax - 1) - x) as u32
+               || scores.bottom == ((ymax - 1) - y) as u32 {
+
+                visible += 1;
+            }
+        }
+    }
+
+    visible.to_string()
+}
+
+fn part2(grid: &Grid<u32>) -> String {
+    let vision_map = get_vision_map(grid, true);
+
+    let (xmax, ymax) = (grid.width() as i32, grid.height() as i32);
+    let mut max_score = 0;
+
+    for y in 0..ymax {
+        for x in 0..xmax {
+            let scores = get_vision_scores(&vision_map, &Point::new(x, y), xmax, ymax);
+
+            let score = scores.top * scores.right * scores.bottom * scores.left;
+            max_score = max(score, max_score);
+        }
+    }
+
+    max_score.to_string()
+}
+
+fn get_vision_scores(vision_map: &VisionMap, point: &Point, xmax: i32, ymax: i32) -> VisionScores {
+    let Point { x, y } = *point;
+    let top = y as usize;
+    let left = x as usize;
+    let right = (xmax - 1 - x) as usize;
+    let bottom = (ymax - 1 - y) as usize;
+
+    VisionScores {
+        top: vision_map.top[left][bottom],
+        bottom: vision_map.bottom[left][top],
+        left: vision_map.left[top][right],
+        right: vision_map.right[top][left],
+    }
+}
+
+fn get_vision_map(grid: &Grid<u32>, inclusive: bool) -> VisionMap {
+    VisionMap {
+        top: calculate_dir_vision_map(grid, Direction::Top, inclusive),
+        bottom: calculate_dir_vision_map(grid, Direction::Bottom, inclusive),
+        left: calculate_dir_vision_map(grid, Direction::Left, inclusive),
+        right: calculate_dir_vision_map(grid, Direction::Right, inclusive),
+    }
+}
+
+fn calculate_dir_vision_map(grid: &Grid<u32>, dir: Direction, inclusive: bool) -> DirVisionMap {
+    let (xmax, ymax) = (grid.width() as i32, grid.height() as i32);
+    let offset_max = match dir {
+        Direction::Top | Direction::Bottom => xmax,
+        Direction::Left | Direction::Right => ymax,
+    };
+
+    let vision_len = match dir {
+        Direction::Top | Direction::Bottom => ymax as usize,
+        Direction::Left | Direction::Right => xmax as usize,
+    };
+    
+    let mut dir_vision_map: Vec<Vec<u32>> = Vec::new();
+
+
+    for offset in 0..offset_max {
+        let DirTraversalParams {
+            point: mut current,
+            delta,
+        } = get_dir_traversal_params(dir, xmax, ymax, offset);
+
+        let mut i = 0;
+        let mut stack: Vec<(u32, usize)> = vec![]; // Monotonic stack (num, index)
+        let mut vision: Vec<u32> = vec![0; vision_len];
+
+        while let Some(&num) = grid.get(current) {
+            while ! stack.is_empty() && stack.last().unwrap().0 <= num {
+                let (_, idx) = stack.pop().unwrap();
+                if inclusive {
+                    vision[idx] = i - (idx as u32);
+                } else {
+                    vision[idx] = i - (idx as u32) - 1;
+                }
+            }
+
+            stack.push((num, i as usize));
+
+            current += delta;
+            i += 1;
+        }
+
+        while let Some((_, idx)) = stack.pop() {
+            
+            vision[idx] = (vision_len - 1 - idx) as u32;
+        }
+
+        dir_vision_map.push(vision);
+    }
+
+    dir_vision_map
+}
+
+struct DirTraversalParams {
+    point: Point,
+    delta: Point,
+}
+
+fn get_dir_traversal_params(dir: Direction, xmax: i32, ymax: i32, offset: i32) -> DirTraversalParams {
+    match dir {
+        Direction::Top => DirTraversalParams { point: Point::new(offset, ymax - 1), delta: Point::new(0, -1) },
+        Direction::Right => DirTraversalParams { point: Point::new(0, offset), delta: Point::new(1, 0) },
+        Direction::Bottom => DirTraversalParams { point: Point::new(offset, 0), delta: Point::new(0, 1) },
+        Direction::Left => DirTraversalParams { point: Point::new(xmax - 1, offset), delta: Point::new(-1, 0) },
+    }
+}
+
+/// A square forest with `size` trees along each side
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| (0..size.max(1)).map(|_| char::from_digit(rng.below(10) as u32, 10).unwrap()).chain(['\n']).collect::<String>())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input), "21");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input), "8");
+    }
+
+    /// The trees from the one at `(x, y)` to the edge in each direction, closest first
+    fn lines_of_sight(rows: &[Vec<u32>], x: usize, y: usize) -> [Vec<u32>; 4] {
+        [
+            (0..y).rev().map(|y| rows[y][x]).collect(),
+            ((y + 1)..rows.len()).map(|y| rows[y][x]).collect(),
+            (0..x).rev().map(|x| rows[y][x]).collect(),
+            ((x + 1)..rows[y].len()).map(|x| rows[y][x]).collect(),
+        ]
+    }
+
+    fn part1_brute_force(rows: &[Vec<u32>]) -> usize {
+        (0..rows.len())
+            .flat_map(|y| (0..rows[y].len()).map(move |x| (x, y)))
+            .filter(|&(x, y)| lines_of_sight(rows, x, y).iter().any(|line| line.iter().all(|&tree| tree < rows[y][x])))
+            .count()
+    }
+
+    fn part2_brute_force(rows: &[Vec<u32>]) -> usize {
+        let viewing_distance = |line: &[u32], height: u32| {
+            line.iter().position(|&tree| tree >= height).map_or(line.len(), |i| i + 1)
+        };
+
+        (0..rows.len())
+            .flat_map(|y| (0..rows[y].len()).map(move |x| (x, y)))
+            .map(|(x, y)| lines_of_sight(rows, x, y).iter().map(|line| viewing_distance(line, rows[y][x])).product())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Forests of up to 8 by 8 trees, with few heights so that there are plenty of trees of the same height
+    fn forest() -> impl Strategy<Value = Vec<Vec<u32>>> {
+        (1..=8usize, 1..=8usize).prop_flat_map(|(width, height)| vec(vec(0..5u32, width), height))
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn part1_matches_brute_force(rows in forest()) {
+            prop_assert_eq!(part1(&Grid::from_rows(rows.clone()).unwrap()), part1_brute_force(&rows).to_string());
+        }
+
+        #[test]
+        fn part2_matches_brute_force(rows in forest()) {
+            prop_assert_eq!(part2(&Grid::from_rows(rows.clone()).unwrap()), part2_brute_force(&rows).to_string());
+        }
+    }
+}
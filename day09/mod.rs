@@ -0,0 +1,336 @@
+use std::{collections::HashSet, time::Duration};
+
+use crate::{geometry::Point, error::{parse_number_in, ParseError}, generate::Rng, render::{self, Frame, Rect, Renderer, Scene}, solution::{Params, Solution}};
+
+type MoveDelta = Point; // dx, dy
+
+#[derive(Debug)]
+pub enum MoveInstruction {
+    Up(i32),
+    Right(i32),
+    Down(i32),
+    Left(i32),
+}
+
+/// The longest move, so that the rope stays well within an `i32` and the moves are simulated in a reasonable time
+const MAX_DISTANCE: i32 = 1000;
+
+
+fn parse(input: &str) -> Result<Vec<MoveInstruction>, ParseError> {
+    let to_move_inst = |d: &str, l: &str| -> Result<MoveInstruction, ParseError> {
+        let distance = parse_number_in(input, l, 0..=MAX_DISTANCE)?;
+
+        match d {
+            "U" => Ok(MoveInstruction::Up(distance)),
+            "R" => Ok(MoveInstruction::Right(distance)),
+            "D" => Ok(MoveInstruction::Down(distance)),
+            "L" => Ok(MoveInstruction::Left(distance)),
+            _ => Err(ParseError::at(input, d, "invalid direction, expected U, R, D or L")),
+        }
+    };
+
+    input
+        .trim()
+        .lines()
+        .map(|x| {
+            let (d, l) = x
+                .trim()
+                .split_once(' ')
+                .ok_or_else(|| ParseError::at(input, x, "expected a direction and a distance"))?;
+
+            to_move_inst(d, l)
+        })
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<MoveInstruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> String {
+        let mut renderer = render::renderer(params, Duration::from_millis(50));
+
+        part2(input, renderer.as_deref_mut())
+    }
+}
+
+fn part1(move_insts: &[MoveInstruction]) -> String {
+    let mut visited_set = HashSet::<Point>::new();
+    visited_set.insert(Point::origin());
+
+    let mut head = Point::origin();
+    let mut tail = Point::origin();
+
+    for inst in move_insts {
+        let mut delta = move_inst_to_move_delta(inst);
+
+        // Execute one move at a time
+        while delta != Point::origin() {
+            let step = delta.signum();
+            head += step;
+                
+            let FollowResult {
+                position,
+                points_visited,
+            } = follow_point(&tail, &head, 1);
+
+            tail = position;
+            points_visited
+                .iter()
+                .for_each(|p| {
+                    visited_set.insert(*p);
+                });
+
+            delta -= step;
+        }
+    }
+
+
+    visited_set.len().to_string()
+}
+
+fn part2(move_insts: &[MoveInstruction], mut renderer: Option<&mut (dyn Renderer + '_)>) -> String {
+    let mut visited_set = HashSet::<Point>::new();
+    visited_set.insert(Point::origin());
+
+    let mut rope: Vec<Point> = vec![Point::origin(); 10];
+    let world = rope_world(move_insts);
+
+    for inst in move_insts {
+        let mut delta = move_inst_to_move_delta(inst);
+
+        // println!("Executing {:?} | {:?}\n", inst, delta);
+        // Execute one move at a time
+        while delta != Point::origin() {
+            let step = delta.signum();
+            // Move the head
+            rope[0] += step;
+
+            // Simulate the tail
+            for i in 0..(rope.len() - 1) { 
+                let (head, tail) = (rope[i], rope[i + 1]);
+                    
+                let FollowResult {
+                    position,
+                    points_visited,
+                } = follow_point(&tail, &head, 1);
+
+                rope[i + 1] = position;
+
+                if i == rope.len() - 2 {
+                    points_visited
+                        .iter()
+                        .for_each(|p| {
+                            visited_set.insert(*p);
+                        });
+                }
+
+            }
+
+            if let Some(renderer) = renderer.as_mut() {
+                renderer.draw_scene(&Scene {
+                    world,
+                    focus: to_world(rope[0]),
+                    draw: &|view| Some(rope_frame(&rope, &visited_set, view)),
+                });
+            }
+
+            delta -= step;
+        }
+    }
+
+    if let Some(renderer) = renderer {
+        renderer.finish_scene(&Scene {
+            world,
+            focus: to_world(rope[0]),
+            draw: &|view| Some(rope_frame(&rope, &visited_set, view)),
+        });
+    }
+
+    visited_set.len().to_string()
+}
+
+fn move_inst_to_move_delta(move_inst: &MoveInstruction) -> MoveDelta {
+    match move_inst {
+        MoveInstruction::Up(d) => Point::new(0, *d),
+        MoveInstruction::Right(d) => Point::new(*d, 0),
+        MoveInstruction::Down(d) => Point::new(0, -*d),
+        MoveInstruction::Left(d) => Point::new(-*d, 0),
+    }
+}
+
+struct FollowResult {
+    position: Point,
+    points_visited: Vec<Point>,
+}
+
+fn follow_point(point: &Point, target: &Point, max_allowed_distance: u32) -> FollowResult {
+    let mut points_visited: Vec<Point> = vec![];
+    let mut position: Point = *point;
+
+    loop {
+        let distance = get_dir_distance(target, &position);
+        let Distance { distance, delta } = distance;
+
+        if distance <= max_allowed_distance {
+            break;
+        }
+
+        // Moves diagonally when both axes differ, and straight otherwise
+        position += delta.signum();
+        points_visited.push(position);
+    }
+
+    FollowResult {
+        position,
+        points_visited,
+    }
+}
+
+struct Distance {
+    distance: u32,
+    delta: MoveDelta,
+}
+
+fn get_dir_distance(a: &Point, b: &Point) -> Distance {
+    let delta = *a - *b;
+    let distance = ((delta.x.pow(2) + delta.y.pow(2)) as f32).sqrt();
+
+    Distance {
+        delta,
+        distance: distance.floor() as u32,
+    }
+}
+
+/// Everything that the head of the rope gets to, the rest of the knots stay inside of it too
+fn rope_world(move_insts: &[MoveInstruction]) -> Rect {
+    let mut head = Point::origin();
+    let (mut min, mut max) = (head, head);
+
+    for inst in move_insts {
+        head += move_inst_to_move_delta(inst);
+        min = Point::new(min.x.min(head.x), min.y.min(head.y));
+        max = Point::new(max.x.max(head.x), max.y.max(head.y));
+    }
+
+    Rect::new(to_world(Point::new(min.x, max.y)), (max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize)
+}
+
+/// The y axis of the rope points up, and down in the frames
+fn to_world(Point { x, y }: Point) -> Point {
+    Point::new(x, -y)
+}
+
+/// The part of the rope and the positions that its tail visited inside of the view,
+/// with `0` marking the origin on the left and bottom edges
+fn rope_frame(knots: &[Point], visited_set: &HashSet<Point>, view: Rect) -> Frame {
+    let to_frame = |point: Point| view.to_frame(to_world(point));
+    let mut frame = view.frame();
+
+    for y in 0..(view.height as i32) {
+        frame.text(Point::new(0, y), &".".repeat(view.width));
+    }
+
+    let origin = to_frame(Point::origin());
+    frame.put(Point::new(0, origin.y), '0');
+    frame.put(Point::new(origin.x, view.height as i32 - 1), '0');
+
+    for &point in visited_set {
+        frame.put(to_frame(point), '#');
+    }
+
+    // The head goes on top
+    for (i, &point) in knots.iter().enumerate().rev() {
+        frame.put(to_frame(point), char::from_digit(i as u32, 10).unwrap());
+    }
+
+    frame
+}
+
+/// `size` moves of the head, of up to 20 steps each
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{} {}\n", rng.pick(&["U", "R", "D", "L"]), rng.range(1..=20)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    // The first example of the puzzle, exampleinput holds the larger one from part 2
+    const SMALL_EXAMPLE: &str = "R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n";
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(part1(&parse(SMALL_EXAMPLE).unwrap()), "13");
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), "88");
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(part2(&parse(SMALL_EXAMPLE).unwrap(), None), "1");
+        assert_eq!(part2(&parse(EXAMPLE).unwrap(), None), "36");
+    }
+
+    #[test]
+    fn parse_rejects_moves_that_are_too_long() {
+        let err = parse("R 2\nU 4294967295\nR 2\n").unwrap_err();
+
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "4294967295"));
+        assert_eq!(err.message, "expected a number from 0 to 1000");
+    }
+
+    #[test]
+    fn follow_point_stays_when_touching() {
+        let tail = Point::new(0, 0);
+
+        for head in tail.neighbours8().chain([tail]) {
+            let result = follow_point(&tail, &head, 1);
+
+            assert_eq!(result.position, tail, "head at {}", head);
+            assert!(result.points_visited.is_empty());
+        }
+    }
+
+    #[test]
+    fn follow_point_moves_straight_when_in_line() {
+        let result = follow_point(&Point::new(1, 1), &Point::new(3, 1), 1);
+
+        assert_eq!(result.position, Point::new(2, 1));
+        assert_eq!(result.points_visited, vec![Point::new(2, 1)]);
+    }
+
+    #[test]
+    fn follow_point_moves_diagonally_when_not_in_line() {
+        let result = follow_point(&Point::new(1, 1), &Point::new(2, 3), 1);
+
+        assert_eq!(result.position, Point::new(2, 2));
+
+        let result = follow_point(&Point::new(0, 0), &Point::new(-2, -1), 1);
+
+        assert_eq!(result.position, Point::new(-1, -1));
+    }
+
+    #[test]
+    fn follow_point_catches_up_from_far_away() {
+        let result = follow_point(&Point::new(0, 0), &Point::new(0, 5), 1);
+
+        assert_eq!(result.position, Point::new(0, 4));
+        assert_eq!(
+            result.points_visited,
+            (1..=4).map(|y| Point::new(0, y)).collect::<Vec<Point>>(),
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use std::time::Duration;
+
+use crate::{geometry::Point, error::{parse_number_in, ParseError}, generate::Rng, render::{self, Frame, Rect, Renderer, Scene}, solution::{Params, Solution}};
+
+#[derive(Debug)]
+pub enum Inst {
+    Noop,
+    Add(i32),
+}
+
+impl Inst {
+    fn cycle_count(&self) -> u32 {
+        match self {
+            Inst::Noop   => 1,
+            Inst::Add(_) => 2,
+        }
+    }
+}
+
+/// The largest value that can be added to X at once, so that X fits in an `i32` over the 240 cycles of the screen
+const MAX_ADDX: i32 = 1_000_000;
+
+fn parse(input: &str) -> Result<Vec<Inst>, ParseError> {
+    input.trim()
+        .lines()
+        .map(|l| {
+             let l = l.trim();
+
+             match l.split_once(' ') {
+                 Some(("addx", v)) => Ok(Inst::Add(parse_number_in(input, v, -MAX_ADDX..=MAX_ADDX)?)),
+                 None if l == "noop" => Ok(Inst::Noop),
+                 _ => Err(ParseError::at(input, l, "invalid instruction, expected addx <n> or noop")),
+             }
+        }).collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Inst>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> String {
+        let mut renderer = render::renderer(params, Duration::from_millis(100));
+
+        part2(input, renderer.as_deref_mut())
+    }
+}
+
+type RegisterState = (
+    i32,   // Register Value
+    usize, // Cycle number 
+);
+
+const SIGNAL_CYCLES: [usize; 6] = [20, 60, 100, 140, 180, 220];
+
+fn part1(insts: &[Inst]) -> String {
+    let mut cycle: usize = 1;
+    let mut register_states: Vec<RegisterState> = vec![(1, cycle)];
+
+    for inst in insts {
+        // Whatever runs after the last signal strength doesn't change it
+        if cycle > SIGNAL_CYCLES[5] {
+            break;
+        }
+
+        cycle += inst.cycle_count() as usize;
+
+        let (prev_register_val, _) = register_states.last().unwrap();
+
+        match inst {
+            Inst::Noop   => register_states.push((*prev_register_val, cycle)),
+            Inst::Add(v) => register_states.push((prev_register_val + v, cycle)),
+        }
+    }
+
+    let result: i64 = SIGNAL_CYCLES
+        .iter()
+        .map(|&cycle| get_cycle_register_state(&register_states, cycle) as i64 * cycle as i64)
+        .sum();
+
+    result.to_string()
+}
+
+
+const SPRITE_SIZE: i32 = 3;
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+const CRT_SIZE: usize = CRT_WIDTH * CRT_HEIGHT;
+const CRT: Rect = Rect { min: Point::new(0, 0), width: CRT_WIDTH, height: CRT_HEIGHT };
+
+fn part2(insts: &[Inst], mut renderer: Option<&mut (dyn Renderer + '_)>) -> String {
+    let mut cycle: usize = 1;
+    let mut sprite_pos: i32 = 1; // The value of the X CPU register (the sprite position)
+
+    let mut screen_buffer = vec!['.'; CRT_SIZE];
+
+    'program: for inst in insts {
+        let inst_cycle_count = inst.cycle_count() as usize;
+
+        for _ in 1..=inst_cycle_count {
+            // Whatever runs after the last pixel isn't shown anywhere
+            if cycle > CRT_SIZE {
+                break 'program;
+            }
+
+            let pixel_x = ((cycle - 1) % CRT_WIDTH) as i32; // The column being drawn during this cycle
+
+            if (pixel_x - sprite_pos).abs() <= SPRITE_SIZE / 2 {
+                screen_buffer[cycle - 1] = '@';
+            }
+
+            if let Some(renderer) = renderer.as_mut() {
+                renderer.draw_scene(&Scene {
+                    world: CRT,
+                    focus: cursor(cycle),
+                    draw: &|view| Some(screen_frame(&screen_buffer, cycle).crop(view)),
+                });
+            }
+
+            cycle += 1;
+        }
+
+        sprite_pos = match inst {
+            Inst::Add(v) => sprite_pos + v,
+            _            => sprite_pos,
+        }
+    }
+
+    if let Some(renderer) = renderer {
+        renderer.finish_scene(&Scene {
+            world: CRT,
+            focus: cursor(cycle),
+            draw: &|view| Some(screen_frame(&screen_buffer, cycle).crop(view)),
+        });
+    }
+
+    let mut output = String::from("\n");
+    output.push_str(&screen_buffer_to_str(&screen_buffer).join("\n"));
+    output
+}
+
+/// The screen with a `|` where the next pixel is drawn
+fn screen_frame(screen_buffer: &[char], cycle: usize) -> Frame {
+    let mut frame = Frame::new(CRT_WIDTH, CRT_HEIGHT);
+
+    for (y, line) in screen_buffer_to_str(screen_buffer).iter().enumerate() {
+        frame.text(Point::new(0, y as i32), line);
+    }
+
+    frame.put(cursor(cycle), '|');
+    frame
+}
+
+/// Where the pixel of the next cycle goes
+fn cursor(cycle: usize) -> Point {
+    Point::new((cycle % CRT_WIDTH) as i32, (cycle / CRT_WIDTH) as i32)
+}
+
+fn screen_buffer_to_str(screen_buffer: &[char]) -> Vec<String> {
+    screen_buffer
+        .chunks(CRT_WIDTH)
+        .map(|x| x.iter().collect::<String>())
+        .collect::<Vec<String>>()
+}
+
+
+// Scuffed binary search to find what the value of the register was at a specific cycle
+fn get_cycle_register_state(register_states: &[RegisterState], cycle: usize) -> i32 {
+    let mut s = 0;
+    let mut e = register_states.len();
+
+    while s < e {
+        let mid = (e - s) / 2 + s;
+        let (val, cc) = register_states[mid];
+
+        if cc == cycle {
+            return val;
+        }
+
+        if cc < cycle {
+            s = mid + 1;            
+            continue;
+        }
+
+        if cc > cycle {
+            e = mid;
+            continue;
+        }
+    }
+
+    register_states[e - 1].0
+}
+
+/// A program that runs for exactly the 240 cycles of the screen, adding up to `size` at once to X
+/// while keeping it around the screen
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1) as i64;
+    let (mut cycle, mut x) = (0, 1);
+    let mut output = String::new();
+
+    while cycle < CRT_SIZE {
+        let value = rng.range((-size).max(-5 - x)..=size.min(45 - x));
+
+        if cycle + 2 > CRT_SIZE || value == 0 || rng.chance(30) {
+            output.push_str("noop\n");
+            cycle += 1;
+        } else {
+            output.push_str(&format!("addx {}\n", value));
+            x += value;
+            cycle += 2;
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), "13140");
+    }
+
+    #[test]
+    fn part1_doesnt_overflow_on_large_values() {
+        assert_eq!(part1(&parse(&"addx 1000000\n".repeat(200)).unwrap()), "56480000720");
+        assert_eq!(parse("noop\naddx 2000000000\n").unwrap_err().text, "2000000000");
+    }
+
+    #[test]
+    fn example_part2() {
+        let expected = "
+@@..@@..@@..@@..@@..@@..@@..@@..@@..@@..
+@@@...@@@...@@@...@@@...@@@...@@@...@@@.
+@@@@....@@@@....@@@@....@@@@....@@@@....
+@@@@@.....@@@@@.....@@@@@.....@@@@@.....
+@@@@@@......@@@@@@......@@@@@@......@@@@
+@@@@@@@.......@@@@@@@.......@@@@@@@.....";
+
+        assert_eq!(part2(&parse(EXAMPLE).unwrap(), None), expected);
+    }
+
+    #[test]
+    fn part2_draws_a_frame_per_cycle() {
+        let mut memory = render::Memory::new();
+        let answer = part2(&parse(EXAMPLE).unwrap(), Some(&mut memory));
+
+        assert_eq!(memory.frames.len(), CRT_SIZE + 1);
+        assert!(memory.finished);
+        assert!(memory.frames[0].to_string().starts_with("@|...."));
+        assert_eq!(format!("\n{}", memory.last().unwrap()), answer);
+    }
+
+    #[test]
+    fn part2_snapshot() {
+        let mut snapshot = render::Snapshot::new();
+        part2(&parse(EXAMPLE).unwrap(), Some(&mut snapshot));
+
+        render::assert_snapshot("day10/snapshots/part2.txt", &snapshot);
+    }
+}
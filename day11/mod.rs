@@ -0,0 +1,505 @@
+use std::str::FromStr;
+
+use crate::{error::{parse_number, parse_number_in, ParseError}, generate::Rng, solution::{Params, Solution}};
+
+type Item = i64;
+
+/// The largest worry level and number in an operation or a test, the least common multiple of the tests can't
+/// be larger either so that the worry levels of part 2 can be multiplied without overflowing
+const MAX_WORRY: Item = 1_000_000_000;
+
+/// What part 1 answers when multiplying the worry levels doesn't fit in an `Item`
+const OVERFLOW: &str = "The worry levels got too large to keep track of";
+
+#[derive(Debug, Clone)]
+enum MonkeyOpVal {
+    Old,
+    Val(i64),
+}
+
+#[derive(Debug, Clone)]
+enum MonkeyOp {
+    Mul(MonkeyOpVal, MonkeyOpVal),
+    Add(MonkeyOpVal, MonkeyOpVal),
+}
+
+impl FromStr for MonkeyOp {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = input.trim().split(' ').collect();
+
+        let [left, op, right] = parts[..] else {
+            return Err(ParseError::at(input, input.trim(), "expected an operation like `old * 19`"));
+        };
+
+        let parse_op_val = |x: &str| match x {
+            "old" => Ok(MonkeyOpVal::Old),
+            s     => Ok(MonkeyOpVal::Val(parse_number_in(input, s, 0..=MAX_WORRY)?)),
+        };
+
+        let result = match op {
+            "+" => MonkeyOp::Add(parse_op_val(left)?, parse_op_val(right)?),
+            "*" => MonkeyOp::Mul(parse_op_val(left)?, parse_op_val(right)?),
+            _   => return Err(ParseError::at(input, op, "unknown operator, expected + or *")),
+        };
+
+        Ok(result)
+    }
+}
+
+impl MonkeyOp {
+    /// `None` when the new worry level doesn't fit in an `Item`
+    fn execute(&self, old: Item) -> Option<Item> {
+
+        let get_val = |v: &MonkeyOpVal| match *v {
+            MonkeyOpVal::Old    => old,
+            MonkeyOpVal::Val(n) => n,
+        };
+
+        match self {
+            MonkeyOp::Mul(a, b) => get_val(a).checked_mul(get_val(b)),
+            MonkeyOp::Add(a, b) => get_val(a).checked_add(get_val(b)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum MonkeyTestOp {
+    DivisibleBy(i64),
+}
+
+impl FromStr for MonkeyTestOp {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if let Some(n) = input.trim().strip_prefix("divisible by ") {
+            return Ok(MonkeyTestOp::DivisibleBy(parse_number_in(input, n, 1..=MAX_WORRY)?));
+        }
+
+        Err(ParseError::at(input, input.trim(), "expected `divisible by <n>`"))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MonkeyTest {
+    op: MonkeyTestOp,
+    if_true: u32,
+    if_false: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    items: Vec<Item>,
+    operation: MonkeyOp,
+    test: MonkeyTest,
+}
+
+#[derive(Debug)]
+struct ThrowResult(Item, u32);
+
+impl Monkey {
+    /// Whether there was an item to inspect, `None` when its worry level got too large
+    fn inspect_item(&mut self, does_get_bored: bool) -> Option<bool> {
+        let item = match self.items.last_mut() {
+            None       => return Some(false),
+            Some(data) => data,
+        };
+
+        // Monkey inspects the first item
+        *item = self.operation.execute(*item)?;
+
+        if does_get_bored {
+            // Monkey gets bored with the item
+            *item /= 3;
+        }
+
+        Some(true)
+    }
+
+    fn throw(&mut self) -> Option<ThrowResult> {
+        let item_to_throw = self.items.pop()?;
+
+
+        let target_monkey = self.test.run(item_to_throw);
+
+        Some(
+            ThrowResult(
+                item_to_throw,
+                target_monkey,
+            )
+        )
+    }
+
+    fn receive(&mut self, item: i64) {
+        self.items.push(item);
+    }
+}
+
+impl MonkeyTest {
+    fn divisor(&self) -> Item {
+        match self.op {
+            MonkeyTestOp::DivisibleBy(n) => n,
+        }
+    }
+
+    fn execute(&self, val: i64) -> i64 {
+        match self.op {
+            MonkeyTestOp::DivisibleBy(n) => val % n,
+        }
+    }
+
+    fn run(&self, val: i64) -> u32 {
+
+        if self.execute(val) == 0 {
+            self.if_true
+        } else {
+            self.if_false
+        }
+    }
+}
+
+
+fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    let blocks: Vec<&str> = input
+        .split("\n\n")
+        .filter(|x| ! x.trim().is_empty())
+        .collect();
+
+    // The monkey business is about the two busiest monkeys
+    if blocks.len() < 2 {
+        return Err(ParseError::end_of_input(input, "expected at least 2 monkeys"));
+    }
+
+    let monkeys = blocks
+        .iter()
+        .enumerate()
+        .map(|(index, block)| parse_monkey(input, block, index, blocks.len()))
+        .collect::<Result<Vec<Monkey>, ParseError>>()?;
+
+    let mut modulus = 1;
+
+    for (monkey, block) in monkeys.iter().zip(&blocks) {
+        modulus = lcm(modulus, monkey.test.divisor());
+
+        if modulus > MAX_WORRY {
+            let test = block.lines().map(str::trim).find(|line| line.starts_with("Test")).unwrap_or(block.trim());
+
+            return Err(ParseError::at(input, test, format!("the tests can't have a least common multiple larger than {}", MAX_WORRY)));
+        }
+    }
+
+    Ok(monkeys)
+}
+
+fn parse_monkey(input: &str, block: &str, index: usize, monkey_count: usize) -> Result<Monkey, ParseError> {
+    let block = block.trim_end();
+    let mut lines = block.lines().map(str::trim).filter(|x| ! x.is_empty());
+
+    // Every line looks like `<name>: <value>`
+    let mut field = |name: &str| {
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::at(input, &block[block.len()..], format!("missing the `{}` line", name)))?;
+
+        line.split_once(':')
+            .filter(|(key, _)| key.starts_with(name))
+            .map(|(_, value)| value.trim())
+            .ok_or_else(|| ParseError::at(input, line, format!("expected `{}: ...`", name)))
+    };
+
+    // Monkey index
+    field("Monkey")?;
+
+    let items_str = field("Starting items")?;
+    let op_str = field("Operation")?;
+    let test_str = field("Test")?;
+    let if_true_str = field("If true")?;
+    let if_false_str = field("If false")?;
+
+    if let Some(line) = lines.next() {
+        return Err(ParseError::at(input, line, "unexpected line, monkeys have to be separated by a blank line"));
+    }
+
+    let items = items_str
+        .split(',')
+        .map(str::trim)
+        .filter(|x| ! x.is_empty())
+        .map(|x| parse_number_in(input, x, 0..=MAX_WORRY))
+        .collect::<Result<Vec<Item>, ParseError>>()?;
+
+    let expr = op_str
+        .strip_prefix("new =")
+        .ok_or_else(|| ParseError::at(input, op_str, "expected `new = <operation>`"))?;
+
+    let operation: MonkeyOp = expr.parse().map_err(|err: ParseError| err.within(input, expr))?;
+    let test_op: MonkeyTestOp = test_str.parse().map_err(|err: ParseError| err.within(input, test_str))?;
+
+    let parse_target = |raw: &str| {
+        let target = raw
+            .strip_prefix("throw to monkey ")
+            .ok_or_else(|| ParseError::at(input, raw, "expected `throw to monkey <n>`"))?;
+
+        let target: u32 = parse_number(input, target)?;
+
+        if target as usize >= monkey_count {
+            return Err(ParseError::at(input, raw, format!("there are only {} monkeys", monkey_count)));
+        }
+
+        // It would keep inspecting and catching the same item forever
+        if target as usize == index {
+            return Err(ParseError::at(input, raw, "a monkey can't throw to itself"));
+        }
+
+        Ok(target)
+    };
+
+    Ok(Monkey {
+        items,
+        operation,
+        test: MonkeyTest {
+            op: test_op,
+            if_true: parse_target(if_true_str)?,
+            if_false: parse_target(if_false_str)?,
+        }
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(input)
+    }
+}
+
+
+fn part1(monkies_orig: &[Monkey]) -> String {
+    let mut monkies = monkies_orig.to_vec();
+    let mut inspected_count: Vec<u64> = vec![0; monkies.len()];
+
+    for _ in 0..20 {
+        for i in 0..monkies.len() {
+            loop {
+                // Dividing by 3 doesn't go along with keeping the worry levels small like part 2 does
+                let Some(inspected) = monkies[i].inspect_item(true) else {
+                    return String::from(OVERFLOW);
+                };
+
+                if ! inspected {
+                    break;
+                }
+
+                inspected_count[i] += 1;
+                if let Some(ThrowResult(item, target_monkey_idx)) = monkies[i].throw() {
+                    monkies[target_monkey_idx as usize].receive(item);
+                }
+            }
+        }
+    }
+
+    monkey_business(inspected_count)
+}
+
+fn part2(monkies_orig: &[Monkey]) -> String {
+    let mut monkies = monkies_orig.to_vec();
+    let mut inspected_count: Vec<u64> = vec![0; monkies.len()];
+
+    // The tests only care about the worry levels modulo their divisors
+    let monkies_divisor = monkies.iter().fold(1, |modulus, mok| lcm(modulus, mok.test.divisor()));
+
+    for _ in 0..10000 {
+        for i in 0..monkies.len() {
+            loop {
+                // The worry levels and the numbers of the operations are at most `MAX_WORRY`, so this never overflows
+                let inspected = monkies[i].inspect_item(false).expect("The worry levels are kept below MAX_WORRY");
+
+                if ! inspected {
+                    break;
+                }
+
+                let last = monkies[i].items.last_mut().unwrap();
+                *last %= monkies_divisor;
+
+                inspected_count[i] += 1;
+                if let Some(ThrowResult(item, target_monkey_idx)) = monkies[i].throw() {
+                    monkies[target_monkey_idx as usize].receive(item);
+                }
+            }
+        }
+    }
+
+    monkey_business(inspected_count)
+}
+
+/// The product of the two largest numbers of inspected items, which can get larger than a `u64` in part 2
+fn monkey_business(mut inspected_count: Vec<u64>) -> String {
+    inspected_count.sort();
+    inspected_count.reverse();
+    let result = inspected_count[0] as u128 * inspected_count[1] as u128;
+
+    result.to_string()
+}
+
+fn lcm(a: Item, b: Item) -> Item {
+    let (mut x, mut y) = (a, b);
+
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+
+    a / x * b
+}
+
+/// `size` monkeys (2 to 9), each testing against a different prime so that their product stays small for part 2.
+/// The operations are drawn again until 20 rounds of part 1 fit in an `Item`
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 9);
+    let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+
+    let items: Vec<Vec<Item>> = (0..count).map(|_| (0..rng.range(1..=6)).map(|_| rng.range(50..=99)).collect()).collect();
+    let targets: Vec<(usize, usize)> = (0..count)
+        .map(|i| {
+            let if_true = (i + 1 + rng.below(count - 1)) % count;
+            let if_false = match (i + 1 + rng.below(count - 1)) % count {
+                target if target == if_true && count > 2 => (0..count).find(|&m| m != i && m != if_true).unwrap(),
+                target => target,
+            };
+
+            (if_true, if_false)
+        })
+        .collect();
+
+    let mut operations: Vec<MonkeyOp> = (0..count).map(|_| random_operation(rng)).collect();
+
+    for _ in 0..100 {
+        if part1_fits(&items, &operations, &primes, &targets) {
+            break;
+        }
+
+        let i = rng.below(count);
+        operations[i] = random_operation(rng);
+    }
+
+    // Without any multiplications the worry levels only ever shrink in part 1
+    if ! part1_fits(&items, &operations, &primes, &targets) {
+        operations = (0..count).map(|_| MonkeyOp::Add(MonkeyOpVal::Old, MonkeyOpVal::Val(rng.range(1..=8)))).collect();
+    }
+
+    let value = |val: &MonkeyOpVal| match val {
+        MonkeyOpVal::Old    => String::from("old"),
+        MonkeyOpVal::Val(n) => n.to_string(),
+    };
+
+    (0..count)
+        .map(|i| {
+            let operation = match &operations[i] {
+                MonkeyOp::Mul(a, b) => format!("{} * {}", value(a), value(b)),
+                MonkeyOp::Add(a, b) => format!("{} + {}", value(a), value(b)),
+            };
+
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                i,
+                items[i].iter().map(Item::to_string).collect::<Vec<String>>().join(", "),
+                operation,
+                primes[i],
+                targets[i].0,
+                targets[i].1,
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn random_operation(rng: &mut Rng) -> MonkeyOp {
+    match rng.below(20) {
+        0..=2 => MonkeyOp::Mul(MonkeyOpVal::Old, MonkeyOpVal::Old),
+        3..=9 => MonkeyOp::Mul(MonkeyOpVal::Old, MonkeyOpVal::Val(rng.range(2..=19))),
+        _     => MonkeyOp::Add(MonkeyOpVal::Old, MonkeyOpVal::Val(rng.range(1..=8))),
+    }
+}
+
+/// Whether 20 rounds of part 1 go without overflowing
+fn part1_fits(items: &[Vec<Item>], operations: &[MonkeyOp], divisors: &[Item], targets: &[(usize, usize)]) -> bool {
+    let mut items = items.to_vec();
+
+    for _ in 0..20 {
+        for i in 0..items.len() {
+            for item in std::mem::take(&mut items[i]) {
+                let item = match &operations[i] {
+                    MonkeyOp::Mul(_, MonkeyOpVal::Old) => item.checked_mul(item),
+                    MonkeyOp::Mul(_, MonkeyOpVal::Val(n)) => item.checked_mul(*n),
+                    MonkeyOp::Add(_, MonkeyOpVal::Old) => item.checked_add(item),
+                    MonkeyOp::Add(_, MonkeyOpVal::Val(n)) => item.checked_add(*n),
+                };
+
+                let Some(item) = item.map(|item| item / 3) else {
+                    return false;
+                };
+
+                let (if_true, if_false) = targets[i];
+                items[if item % divisors[i] == 0 { if_true } else { if_false }].push(item);
+            }
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input), "10605");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input), "2713310158");
+    }
+
+    #[test]
+    fn parse_rejects_monkeys_that_throw_to_themselves() {
+        let input = EXAMPLE.replacen("If false: throw to monkey 3", "If false: throw to monkey 0", 1);
+        let err = parse(&input).unwrap_err();
+
+        assert_eq!((err.line, err.text.as_str()), (6, "throw to monkey 0"));
+        assert_eq!(err.message, "a monkey can't throw to itself");
+    }
+
+    #[test]
+    fn parse_rejects_tests_that_part2_cant_keep_small() {
+        let input = EXAMPLE.replacen("divisible by 23", "divisible by 999999999", 1);
+        let err = parse(&input).unwrap_err();
+
+        assert_eq!((err.line, err.text.as_str()), (11, "Test: divisible by 19"));
+    }
+
+    #[test]
+    fn part1_answers_when_the_worry_levels_overflow() {
+        let input = EXAMPLE.replacen("old * 19", "old * old", 1).replacen("79, 98", "1000000000", 1);
+
+        assert_eq!(part1(&parse(&input).unwrap()), OVERFLOW);
+        part2(&parse(&input).unwrap());
+    }
+}
@@ -0,0 +1,348 @@
+use std::collections::{HashMap, VecDeque, HashSet};
+use std::time::Duration;
+
+use crate::{geometry::Point, grid::Grid, error::ParseError, generate::Rng, render::{self, Color, Frame, Rect, Renderer, Scene, Style}, solution::{Params, Solution}};
+
+type PointInfo = (
+    i32, // Distance
+    Point, // Prev
+);
+
+pub struct ElevationMap {
+    start: Point,
+    end: Point,
+    grid: Grid<char>,
+}
+
+const START_STYLE: Style = Style::fg(Color::Red);
+const END_STYLE: Style = Style::fg(Color::Green);
+const PATH_STYLE: Style = Style::fg(Color::Red);
+
+/// The height map with the points that the search got to so far shown as `.`
+fn height_map_frame(em: &ElevationMap, visited: &HashSet<Point>) -> Frame {
+    let ElevationMap {
+        start,
+        end,
+        grid,
+    } = em;
+
+    let mut frame = Frame::new(grid.width(), grid.height());
+
+    for (point, &height) in grid.iter() {
+        if visited.contains(&point) {
+            frame.put(point, '.');
+        } else {
+            frame.put(point, height);
+        }
+    }
+
+    frame.put_styled(*start, 'S', START_STYLE);
+    frame.put_styled(*end, 'E', END_STYLE);
+    frame
+}
+
+/// The height map with the path marked by `*`, and its length below it
+fn path_frame(em: &ElevationMap, path: &[Point]) -> Frame {
+    let ElevationMap {
+        start,
+        end,
+        grid,
+    } = em;
+
+    let mut frame = Frame::new(grid.width().max(20), grid.height() + 3);
+
+    for (point, &height) in grid.iter() {
+        frame.put(point, height);
+    }
+
+    for &point in &path[..(path.len() - 1)] {
+        frame.put_styled(point, '*', PATH_STYLE);
+    }
+
+    frame.put_styled(*start, 'S', START_STYLE);
+    frame.put_styled(*end, 'E', END_STYLE);
+    frame.text(Point::new(0, grid.height() as i32 + 2), &format!("Path length = {}", path.len() - 1));
+    frame
+}
+
+fn parse(input: &str) -> Result<ElevationMap, ParseError> {
+    let lines: Vec<&str> = input.trim()
+        .lines()
+        .map(str::trim)
+        .collect();
+
+    let rows = lines
+        .iter()
+        .map(|x| x
+             .char_indices()
+             .map(|(i, c)| match c {
+                 'a'..='z' | 'S' | 'E' => Ok(c),
+                 _ => Err(ParseError::at(input, &x[i..(i + c.len_utf8())], "expected a height between a and z, S or E")),
+             })
+             .collect::<Result<Vec<char>, ParseError>>())
+        .collect::<Result<Vec<Vec<char>>, ParseError>>()?;
+
+    let mut grid = Grid::from_rows(rows)
+        .map_err(|err| ParseError::at(input, lines[err.row], err.to_string()))?;
+
+    let start = grid.position(&'S').ok_or_else(|| ParseError::end_of_input(input, "the starting point S was not found"))?;
+    let end   = grid.position(&'E').ok_or_else(|| ParseError::end_of_input(input, "the destination point E was not found"))?;
+    
+    grid[start] = 'a';
+    grid[end] = 'z';
+
+    Ok(ElevationMap {
+        start,
+        end,
+        grid,
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = ElevationMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> String {
+        let mut renderer = render::renderer(params, Duration::ZERO);
+
+        part1(input, renderer.as_deref_mut())
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> String {
+        let mut renderer = render::renderer(params, Duration::ZERO);
+
+        part2(input, renderer.as_deref_mut())
+    }
+}
+
+fn part1(em: &ElevationMap, mut renderer: Option<&mut (dyn Renderer + '_)>) -> String {
+    let Some(shortest_path) = find_shortest_path(em, false, renderer.as_deref_mut()) else {
+        return String::from("Not found");
+    };
+    let len = shortest_path.len() - 1;
+
+    if let Some(renderer) = renderer {
+        let frame = path_frame(em, &shortest_path);
+
+        renderer.finish_scene(&Scene { world: frame.rect(), focus: em.end, draw: &|view| Some(frame.crop(view)) });
+    }
+
+    len.to_string()
+}
+
+fn part2(em: &ElevationMap, mut renderer: Option<&mut (dyn Renderer + '_)>) -> String {
+    let Some(shortest_path) = find_shortest_path(em, true, renderer.as_deref_mut()) else {
+        return String::from("Not found");
+    };
+    let len = shortest_path.len() - 1;
+
+    if let Some(renderer) = renderer {
+        let frame = path_frame(em, &shortest_path);
+
+        renderer.finish_scene(&Scene { world: frame.rect(), focus: em.end, draw: &|view| Some(frame.crop(view)) });
+    }
+
+    len.to_string()
+}
+
+fn find_shortest_path(em: &ElevationMap, to_closest_start: bool, mut renderer: Option<&mut (dyn Renderer + '_)>) -> Option<Vec<Point>> {
+    let ElevationMap {
+        start,
+        end,
+        grid,
+    } = em;
+
+    let start_height = get_point_height(grid, start);
+
+    // Dijkstra's algo
+    let mut visited = HashSet::<Point>::new();
+    let mut pim = HashMap::<Point, PointInfo>::new(); // Point info map
+    let mut queue = VecDeque::<Point>::new();
+    queue.push_back(*end);
+    pim.insert(*end, (0, *end));
+    visited.insert(*end);
+    
+    while !queue.is_empty() {
+        let point = queue.pop_front().unwrap();
+
+        let get_neighbors = || -> Vec<Point> {
+            if to_closest_start && get_point_height(grid, &point) == start_height {
+                return vec![];
+            }
+
+            get_point_neighbors(grid, &point)
+                .into_iter()
+                .filter(|p| ! visited.contains(p))
+                .collect()
+        };
+
+        let prev_point_info = *pim.get(&point).unwrap();
+
+        if let Some(renderer) = renderer.as_mut() {
+            renderer.draw_scene(&Scene {
+                world: Rect::new(Point::origin(), grid.width(), grid.height()),
+                focus: point,
+                draw: &|view| Some(height_map_frame(em, &visited).crop(view)),
+            });
+        }
+
+        for np in get_neighbors() {
+            let prev_distance = pim.get(&np).map_or(i32::MAX, |x| x.0);
+
+            let curr_dist = prev_point_info.0 + 1;
+
+            if curr_dist < prev_distance {
+                pim.insert(np, (curr_dist, point));
+            }
+
+            queue.push_back(np);
+            visited.insert(np);
+        }
+    }
+
+    if to_closest_start {
+        get_shortest_path_to_height(em, &pim, start_height)
+    } else {
+        get_shortest_path_from_pim(&pim, end, start)
+    }
+}
+
+fn get_point_neighbors(
+    grid: &Grid<char>,
+    point: &Point,
+) -> Vec<Point> {
+    grid.neighbours4(*point)
+        .filter(|c|
+            get_point_height(grid, c) as u32 + 1
+            >= get_point_height(grid, point) as u32
+        ).collect()
+}
+
+fn get_shortest_path_from_pim(
+    pim: &HashMap::<Point, PointInfo>,
+    start: &Point,
+    end: &Point,
+) -> Option<Vec<Point>> {
+
+    let mut current_point = end;
+    let mut path = vec![*end];
+
+    while *current_point != *start {
+        let p = pim.get(current_point)?;
+
+        path.push(p.1);
+        current_point = &p.1;
+    }
+
+    Some(path.into_iter().rev().collect())
+}
+
+fn get_shortest_path_to_height(
+    em: &ElevationMap,
+    pim: &HashMap::<Point, PointInfo>,
+    height: char,
+) -> Option<Vec<Point>> {
+    let ElevationMap {
+        start: _,
+        end,
+        grid,
+    } = em;
+
+    /*
+     * We could just get return the shortest distance here by fetching directly from pim
+     * but we need the full paths for rendering
+     */
+    let lowest_points: Vec<Point> = pim
+        .keys()
+        .filter_map(|&p| {
+            if get_point_height(grid, &p) == height {
+                return Some(p)
+            }
+
+            None
+        }).collect();
+
+    lowest_points
+        .iter()
+        .filter_map(|p| get_shortest_path_from_pim(pim, end, p))
+        .min_by(|x, y| x.cmp(y))
+}
+
+fn get_point_height(grid: &Grid<char>, point: &Point) -> char {
+    grid[*point]
+}
+
+/// A heightmap `size` columns wide (at least 26) with a path from S on the left to E on the right, that climbs
+/// one step at a time so that it can always be walked. The rest of the heights are random
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(26);
+    let height = (width / 4).max(5);
+
+    // Down each column to a random row, then right to the next one
+    let mut y = rng.below(height);
+    let mut path = vec![(0, y)];
+
+    for x in 0..width {
+        let target = rng.below(height);
+
+        while y != target {
+            y = if target > y { y + 1 } else { y - 1 };
+            path.push((x, y));
+        }
+
+        if x + 1 < width {
+            path.push((x + 1, y));
+        }
+    }
+
+    let mut rows: Vec<Vec<char>> = (0..height).map(|_| (0..width).map(|_| rng.letter('z')).collect()).collect();
+
+    for (i, &(x, y)) in path.iter().enumerate() {
+        rows[y][x] = (b'a' + (25 * i / (path.len() - 1)) as u8) as char;
+    }
+
+    let (start, end) = (path[0], path[path.len() - 1]);
+    rows[start.1][start.0] = 'S';
+    rows[end.1][end.0] = 'E';
+
+    rows.iter().map(|row| row.iter().collect::<String>() + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input, None), "31");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input, None), "29");
+    }
+
+    #[test]
+    fn path_snapshots() {
+        let input = parse(EXAMPLE).unwrap();
+
+        let mut snapshot = render::Snapshot::new();
+        part1(&input, Some(&mut snapshot));
+        render::assert_snapshot("day12/snapshots/part1.txt", &snapshot);
+
+        let mut snapshot = render::Snapshot::new();
+        part2(&input, Some(&mut snapshot));
+        render::assert_snapshot("day12/snapshots/part2.txt", &snapshot);
+    }
+}
@@ -0,0 +1,414 @@
+use std::{fmt, str::FromStr, cmp::{Ord, Ordering}};
+
+use crate::{error::ParseError, generate::Rng, solution::{Params, Solution}};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Packet {
+    List(Vec<Packet>),
+    Value(i32),
+}
+
+/// Where parsing a packet failed, in characters from the start of the packet
+#[derive(Debug, PartialEq, Eq)]
+struct ParsePacketError {
+    position: usize,
+    len: usize,
+    message: &'static str,
+}
+
+impl ParsePacketError {
+    fn at(position: usize, len: usize, message: &'static str) -> Self {
+        ParsePacketError { position, len, message }
+    }
+}
+
+/// How deep lists can be nested, parsing and comparing packets recurse once per level
+const MAX_DEPTH: usize = 100;
+
+impl Packet {
+    fn collect_list(s: &[char], i: usize) -> Result<usize, ParsePacketError> {
+
+        let mut j = 0;
+        let mut openings = 0;
+
+        loop {
+            let c = *s.get(j + i).ok_or(ParsePacketError::at(i, 1, "this list is never closed"))?;
+
+            if c == '[' {
+                openings += 1;
+            }
+
+            if c == ']' {
+                openings -= 1;
+            }
+
+            if openings == 0 {
+                break;
+            }
+
+            j += 1;
+        }
+
+        Ok(j)
+    }
+
+    fn parse_num(s: &[char], i: &mut usize) -> Result<Self, ParsePacketError> {
+        let digits = s[*i..].iter().take_while(|x| x.is_ascii_digit()).collect::<String>();
+
+        let val = digits
+            .parse()
+            .map_err(|_| ParsePacketError::at(*i, digits.len(), "invalid number"))?;
+
+        *i += digits.len();
+
+        Ok(Packet::Value(val))
+    }
+
+    fn consume_whitespace(s: &[char], i: &mut usize) {
+        while s.get(*i).is_some_and(|c| c.is_whitespace()) { *i += 1 }
+    }
+
+    fn consume_comma(s: &[char], i: &mut usize) -> Result<(), ParsePacketError> {
+        if *i >= s.len() {
+            return Ok(());
+        }
+
+        if s[*i] != ',' {
+            return Err(ParsePacketError::at(*i, 1, "expected a comma"));
+        }
+
+        *i += 1; // Consume the comma
+
+        Ok(())
+    }
+
+    fn to_list(&self) -> Vec<Self> {
+        match self {
+            Packet::Value(n) => vec![Packet::Value(*n)],
+            Packet::List(l)  => l.clone(),
+        }
+    }
+
+    fn parse_list(s: &[char], i: &mut usize, depth: usize) -> Result<Self, ParsePacketError> {
+        if depth > MAX_DEPTH {
+            return Err(ParsePacketError::at(*i, 1, "lists can't be nested more than 100 deep"));
+        }
+
+        let list_len = Packet::collect_list(s, *i)?;
+        
+        let mut list: Vec<Packet> = vec![];
+
+        let j = *i;
+        *i += 1; // Consume [
+        while *i < j + list_len {
+            Packet::consume_whitespace(s, i);
+
+            match s[*i] {
+                '[' => list.push(Packet::parse_list(s, i, depth + 1)?),
+                c if c.is_ascii_digit() => list.push(Packet::parse_num(s, i)?),
+                ']' if list.is_empty() => break, // Empty list
+                _ => return Err(ParsePacketError::at(*i, 1, "expected a number or a list")),
+            }
+
+            Packet::consume_whitespace(s, i);
+
+            if s[*i] == ']' {
+                break;
+            }
+
+            Packet::consume_comma(s, i)?;
+        }
+        *i += 1; // Consume ]
+
+        Ok(Packet::List(list))
+    }
+
+    fn parse(s: &[char]) -> Result<Self, ParsePacketError> {
+        if s.first() != Some(&'[') {
+            return Err(ParsePacketError::at(0, 1, "a packet has to be a list"));
+        }
+
+        let mut i = 0;
+        let packet = Packet::parse_list(s, &mut i, 1)?;
+
+        if i < s.len() {
+            return Err(ParsePacketError::at(i, s.len() - i, "unexpected characters after the end of the packet"));
+        }
+
+        Ok(packet)
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+
+        Packet::parse(&trimmed.chars().collect::<Vec<char>>()).map_err(|err| {
+            // The error is in characters, the slice has to be in bytes
+            let byte_offset = |n: usize| trimmed.char_indices().nth(n).map_or(trimmed.len(), |(i, _)| i);
+            let text = &trimmed[byte_offset(err.position)..byte_offset(err.position + err.len)];
+
+            ParseError::at(s, text, err.message)
+        })
+    }
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Value(n) => write!(f, "{}", n),
+            Packet::List(list) => {
+                write!(f, "[")?;
+
+                for (i, packet) in list.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{}", packet)?;
+                }
+
+                write!(f, "]")
+            },
+        }
+    }
+}
+impl Eq for Packet {}
+
+impl PartialOrd for Packet {
+    // I have no clue why I this isn't implied automatically since I've implemented the Ord trait
+    // Not having this makes the comparison operators (<, >, etc) not work properly
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if let (Self::Value(a), Self::Value(b)) = (self, other) { return a.cmp(b) }
+        
+
+        let p1 = self.to_list();
+        let p2 = other.to_list();
+
+        let mut i = 0;
+        let mut j = 0;
+
+        while i < p1.len() && j < p2.len() {
+            let ord = p1[i].cmp(&p2[i]);
+
+            if ord != Ordering::Equal {
+                return ord;
+            }
+            
+            i += 1;
+            j += 1;
+        }
+
+        if p1.len() < p2.len() {
+            Ordering::Less
+        } else if p2.len() < p1.len() {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+}
+
+type PacketPair = (Packet, Packet);
+
+fn parse(input: &str) -> Result<Vec<PacketPair>, ParseError> {
+    let parse_packet = |s: &str| Packet::from_str(s).map_err(|err| err.within(input, s));
+
+    input.trim()
+        .split("\n\n")
+        .map(|ppstr| {
+             let pair = ppstr.split('\n').collect::<Vec<&str>>();
+
+             let [first, second] = pair[..] else {
+                 return Err(ParseError::at(input, ppstr, "expected 2 packets followed by a blank line"));
+             };
+
+             Ok((parse_packet(first)?, parse_packet(second)?))
+        })
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<PacketPair>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(input)
+    }
+}
+
+fn part1(pps: &[PacketPair]) -> String {
+    let mut idxs: Vec<usize> = Vec::new();
+
+    for (i, item) in pps.iter().enumerate() {
+        let (p1, p2) = item;
+
+        if p1 < p2 {
+            idxs.push(i + 1);
+        }
+    }
+
+    let result: usize = idxs.iter().sum();
+    result.to_string()
+}
+
+fn part2(pps: &[PacketPair]) -> String {
+    let mut packets: Vec<Packet> = pps
+        .iter()
+        .flat_map(|x| vec![x.0.clone(), x.1.clone()])
+        .collect();
+
+    packets.sort();
+
+    let mut idxs: Vec<usize> = Vec::new();
+    
+    let divider_packets = [Packet::List(vec![Packet::List(vec![Packet::Value(2)])]),
+        Packet::List(vec![Packet::List(vec![Packet::Value(6)])])];
+
+    let mut i = 0; // packets index
+    let mut j = 0; // divider_packets index
+    
+    while j < divider_packets.len() && i < packets.len() {
+        if divider_packets[j] < packets[i] {
+            idxs.push(i + j);
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    let result: usize = idxs
+        .iter()
+        .map(|x| x + 1) // Add one cuz the challenge starts counting from 1
+        .product();
+    result.to_string()
+}
+
+/// `size` pairs of packets, nested up to 4 lists deep
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{}\n{}\n", random_list(rng, 4), random_list(rng, 4)))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn random_list(rng: &mut Rng, depth: usize) -> String {
+    let items: Vec<String> = (0..rng.below(6))
+        .map(|_| match depth > 1 && rng.chance(30) {
+            true  => random_list(rng, depth - 1),
+            false => rng.range(0..=10).to_string(),
+        })
+        .collect();
+
+    format!("[{}]", items.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    fn packet(s: &str) -> Packet {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), "13");
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), "140");
+    }
+
+    #[test]
+    fn cmp_matches_the_example_pairs() {
+        let pairs = [
+            ("[1,1,3,1,1]", "[1,1,5,1,1]", Ordering::Less),
+            ("[[1],[2,3,4]]", "[[1],4]", Ordering::Less),
+            ("[9]", "[[8,7,6]]", Ordering::Greater),
+            ("[[4,4],4,4]", "[[4,4],4,4,4]", Ordering::Less),
+            ("[7,7,7,7]", "[7,7,7]", Ordering::Greater),
+            ("[]", "[3]", Ordering::Less),
+            ("[[[]]]", "[[]]", Ordering::Greater),
+            ("[1,[2,[3,[4,[5,6,7]]]],8,9]", "[1,[2,[3,[4,[5,6,0]]]],8,9]", Ordering::Greater),
+        ];
+
+        for (left, right, expected) in pairs {
+            assert_eq!(packet(left).cmp(&packet(right)), expected, "{} vs {}", left, right);
+            assert_eq!(packet(right).cmp(&packet(left)), expected.reverse(), "{} vs {}", right, left);
+        }
+    }
+
+    #[test]
+    fn cmp_wraps_values_in_lists() {
+        assert_eq!(packet("[1,[2]]").cmp(&packet("[[1],2]")), Ordering::Equal);
+        assert_eq!(packet("[[[3]]]").cmp(&packet("[3]")), Ordering::Equal);
+    }
+
+    #[test]
+    fn cmp_compares_numbers_by_value() {
+        assert_eq!(packet("[10]").cmp(&packet("[9]")), Ordering::Greater);
+        assert_eq!(packet("[2,10]").cmp(&packet("[2,10]")), Ordering::Equal);
+    }
+
+    #[test]
+    fn parse_reports_where_a_packet_is_broken() {
+        let err = "[1,2]\n[1,x]\n".parse::<Packet>().unwrap_err();
+        assert_eq!(err.message, "unexpected characters after the end of the packet");
+
+        let err = parse("[1,2]\n[1,x]\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, "x"));
+    }
+
+    #[test]
+    fn parse_rejects_lists_nested_too_deep() {
+        let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+
+        assert!(nested(MAX_DEPTH).parse::<Packet>().is_ok());
+
+        let err = nested(100_000).parse::<Packet>().unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (MAX_DEPTH + 1, "lists can't be nested more than 100 deep"));
+    }
+
+    /// Packets with lists nested up to 6 deep, and numbers of any size
+    fn packets() -> impl Strategy<Value = Packet> {
+        let leaf = prop_oneof![(0..=i32::MAX).prop_map(Packet::Value), Just(Packet::List(Vec::new()))];
+
+        leaf.prop_recursive(5, 64, 6, |inner| vec(inner, 0..6).prop_map(Packet::List))
+            .prop_map(|packet| match packet {
+                Packet::Value(_) => Packet::List(vec![packet]),
+                list => list,
+            })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn packets_round_trip_through_display(packet in packets()) {
+            prop_assert_eq!(packet.to_string().parse::<Packet>(), Ok(packet));
+        }
+    }
+}
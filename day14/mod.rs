@@ -0,0 +1,434 @@
+use std::{collections::{HashSet, HashMap}, cmp, time::Duration};
+
+use crate::{geometry::{Point, Range}, error::{parse_number_in, ParseError}, generate::Rng, render::{self, Color, Frame, Rect, Renderer, Scene, Style}, solution::{Params, Solution}};
+
+type Path = Vec<Point>;
+
+// I couldn't find a better name
+#[derive(Debug)]
+pub struct CaveReservoir {
+    sand_source: Point,
+    rock_paths: Vec<Path>,
+}
+
+/// How far right the rocks can be
+const MAX_X: i32 = 1000;
+
+/// How deep the rocks can be, the sand of part 2 piles up into a triangle as deep as the cave so it takes
+/// a while to fill a deeper one
+const MAX_DEPTH: i32 = 200;
+
+fn parse(input: &str) -> Result<Vec<Path>, ParseError> {
+    if input.trim().is_empty() {
+        return Err(ParseError::end_of_input(input, "expected at least one rock path"));
+    }
+
+    input.trim()
+        .lines()
+        .map(|l| {
+             let raw_points = l.trim().split(" -> ").collect::<Vec<&str>>();
+
+             let path = raw_points
+                 .iter()
+                 .map(|&p| {
+                     let (x, y) = p
+                         .split_once(',')
+                         .ok_or_else(|| ParseError::at(input, p, "expected a point like 498,4"))?;
+
+                     Ok(Point::new(parse_number_in(input, x, 0..=MAX_X)?, parse_number_in(input, y, 0..=MAX_DEPTH)?))
+                 }).collect::<Result<Path, ParseError>>()?;
+
+             // Walls are either horizontal or vertical
+             if let Some(i) = path.windows(2).position(|w| w[0].x != w[1].x && w[0].y != w[1].y) {
+                 return Err(ParseError::at(input, raw_points[i + 1], "diagonal paths aren't supported"));
+             }
+
+             Ok(path)
+         }).collect()
+}
+
+struct LineTraversalParams {
+    start: Point,
+    end: Point,
+    dx: i32,
+    dy: i32,
+}
+
+fn get_line_traversal_params(a: &Point, b: &Point) -> LineTraversalParams {
+    // A path can stay in place, that's still a single rock
+    if a.x == b.x && a.y <= b.y {
+        return LineTraversalParams { start: *a, end: *b, dx: 0, dy: 1 };
+    }
+
+    if a.x == b.x && a.y > b.y {
+        return LineTraversalParams { start: *b, end: *a, dx: 0, dy: 1 };
+    }
+
+    if a.y == b.y && a.x < b.x {
+        return LineTraversalParams { start: *a, end: *b, dx: 1, dy: 0 };
+    }
+
+    if a.y == b.y && a.x > b.x {
+        return LineTraversalParams { start: *b, end: *a, dx: 1, dy: 0 };
+    }
+
+    panic!("Unsupported traversal a: {:?} - b: {:?}", a, b);
+}
+
+struct WallsMinMax {
+    min: Point,
+    max: Point,
+}
+
+fn get_walls_min_max(paths: &[Path]) -> WallsMinMax {
+    let rock_paths_iter = paths.iter().flatten();
+
+    let min_x = rock_paths_iter.clone().min_by(|a, b| a.x.cmp(&b.x)).unwrap().x;
+    let max_x = rock_paths_iter.clone().max_by(|a, b| a.x.cmp(&b.x)).unwrap().x;
+    let min_y = rock_paths_iter.clone().min_by(|a, b| a.y.cmp(&b.y)).unwrap().y;
+    let max_y = rock_paths_iter.clone().max_by(|a, b| a.y.cmp(&b.y)).unwrap().y;
+
+    
+
+    WallsMinMax {
+        min: Point::new(min_x, min_y),
+        max: Point::new(max_x, max_y),
+    }
+}
+
+/// The rocks and the faucet, with some room around them
+fn cave_world(data: &CaveReservoir) -> Rect {
+    let CaveReservoir {
+        sand_source,
+        rock_paths,
+    } = data;
+    let walls_mm = get_walls_min_max(rock_paths);
+
+    let min_x = cmp::min(walls_mm.min.x, sand_source.x);
+    let max_x = cmp::max(walls_mm.max.x, sand_source.x);
+    let min_y = cmp::min(walls_mm.min.y, sand_source.y) - 2;
+    let max_y = cmp::max(walls_mm.max.y, sand_source.y) + 4;
+
+    Rect::new(Point::new(min_x, min_y), cmp::max(max_x - min_x, 30) as usize, (max_y - min_y) as usize)
+}
+
+/// The faucet (`+`), the rocks (`#`) and the sand (`o`) inside of the view, with the falling particle in red
+fn cave_frame(data: &CaveReservoir, rested: &HashSet<Point>, current: &Point, view: Rect) -> Frame {
+    let CaveReservoir {
+        sand_source,
+        rock_paths,
+    } = data;
+    let offset = -view.min;
+    let mut frame = view.frame();
+
+    // Draw the sand faucet
+    frame.put(*sand_source + offset, '+');
+
+    // Draw walls
+    for path in rock_paths {
+        let mut p = path[0];
+        for &c in &path[1..] {
+            let LineTraversalParams {
+                mut start,
+                end,
+                dx,
+                dy,
+            } = get_line_traversal_params(&p, &c);
+
+            while start.x <= end.x && start.y <= end.y {
+                frame.put(start + offset, '#');
+
+                start.x += dx;
+                start.y += dy;
+            }
+
+            p = c;
+        }
+    }
+
+
+    // Draw rested sand particles
+    for &point in rested.iter() {
+        frame.put(point + offset, 'o');
+    }
+
+
+    // Draw sand particle being simulated
+    frame.put_styled(*current + offset, 'o', Style::fg(Color::Red));
+
+    frame
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = CaveReservoir;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(CaveReservoir {
+            sand_source: Point::new(500, 0),
+            rock_paths: parse(input)?,
+        })
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> String {
+        let mut renderer = render::renderer(params, Duration::from_millis(4));
+
+        part1(input, renderer.as_deref_mut())
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> String {
+        let mut renderer = render::renderer(params, Duration::from_millis(4));
+
+        part2(input, renderer.as_deref_mut())
+    }
+}
+
+fn part1(data: &CaveReservoir, mut renderer: Option<&mut (dyn Renderer + '_)>) -> String {
+    let wall = RockWall::from_rock_paths(&data.rock_paths);
+    let lowest_y = get_walls_min_max(&data.rock_paths).max.y;
+    let mut rested = HashSet::<Point>::new();
+    // Where the last particle came to rest, the camera follows the sand as it piles up
+    let mut last_rested = data.sand_source;
+    let world = cave_world(data);
+
+    let mut sand_particle = data.sand_source;
+
+    // Keep simulating until a sand particle goes below the lowest y point (the floor)
+    while sand_particle.y < lowest_y {
+        let Point { x, y } = sand_particle;
+
+        let possible_moves = [
+            Point::new(x, y + 1),
+            Point::new(x - 1, y + 1),
+            Point::new(x + 1, y + 1),
+        ];
+
+        let next_pos = possible_moves
+            .iter()
+            .find(|p| ! wall.is_colliding(p) && ! rested.contains(p));
+
+        match next_pos {
+            Some(p) => sand_particle = *p,
+            None    => {
+                rested.insert(sand_particle);
+                last_rested = sand_particle;
+
+                // The rocks can catch all of the sand, until it blocks the faucet
+                if sand_particle == data.sand_source {
+                    break;
+                }
+
+                sand_particle = data.sand_source;
+            },
+        }
+
+        if let Some(renderer) = renderer.as_mut() {
+            renderer.draw_scene(&Scene {
+                world,
+                focus: last_rested,
+                // The particles outside of the view would only slow things down
+                draw: &|view| view.contains(sand_particle).then(|| cave_frame(data, &rested, &sand_particle, view)),
+            });
+        }
+    }
+
+    if let Some(renderer) = renderer {
+        renderer.finish_scene(&Scene {
+            world,
+            focus: last_rested,
+            draw: &|view| Some(cave_frame(data, &rested, &sand_particle, view)),
+        });
+    }
+
+    rested.len().to_string()
+}
+
+fn part2(orig_data: &CaveReservoir, mut renderer: Option<&mut (dyn Renderer + '_)>) -> String {
+    let walls_mm = get_walls_min_max(&orig_data.rock_paths);
+    let mut full_rock_paths: Vec<Path> = orig_data.rock_paths.clone();
+
+    // The sand piles up in a triangle below the faucet, so the floor only needs to be as wide as its base
+    let floor_y = walls_mm.max.y + 2;
+    let source_x = orig_data.sand_source.x;
+    let floor: Path = vec![Point::new(source_x - floor_y - 1, floor_y), Point::new(source_x + floor_y + 1, floor_y)];
+
+    full_rock_paths.push(floor);
+    let data = &CaveReservoir { sand_source: orig_data.sand_source, rock_paths: full_rock_paths };
+
+    let wall = RockWall::from_rock_paths(&data.rock_paths);
+    let mut rested = HashSet::<Point>::new();
+    // Where the last particle came to rest, the camera follows the sand as it piles up
+    let mut last_rested = data.sand_source;
+    let world = cave_world(data);
+
+    let mut sand_particle = data.sand_source;
+
+    loop {
+        let Point { x, y } = sand_particle;
+
+        let possible_moves = [
+            Point::new(x, y + 1),
+            Point::new(x - 1, y + 1),
+            Point::new(x + 1, y + 1),
+        ];
+
+        let next_pos = possible_moves
+            .iter()
+            .find(|p| ! wall.is_colliding(p) && ! rested.contains(p));
+
+        match next_pos {
+            Some(p) => sand_particle = *p,
+            None    => {
+                rested.insert(sand_particle);
+                last_rested = sand_particle;
+
+                // Keep simulating until a sand particle blocks the faucet
+                if sand_particle == data.sand_source {
+                    break;
+                }
+
+                sand_particle = data.sand_source;
+            },
+        }
+
+        if let Some(renderer) = renderer.as_mut() {
+            renderer.draw_scene(&Scene {
+                world,
+                focus: last_rested,
+                // The particles outside of the view would only slow things down
+                draw: &|view| view.contains(sand_particle).then(|| cave_frame(data, &rested, &sand_particle, view)),
+            });
+        }
+    }
+
+    if let Some(renderer) = renderer {
+        renderer.finish_scene(&Scene {
+            world,
+            focus: last_rested,
+            draw: &|view| Some(cave_frame(data, &rested, &sand_particle, view)),
+        });
+    }
+
+    rested.len().to_string()
+}
+
+#[derive(Debug)]
+struct RockWall {
+    vertical: HashMap<i32, HashSet<Range>>,   // x: [Range { start: y_start, end: y_end }]
+    horizontal: HashMap<i32, HashSet<Range>>, // y: [Range { start: x_start, end: x_end }]
+}
+
+impl RockWall {
+    fn is_colliding(&self, point: &Point) -> bool {
+        let Point { x, y } = *point;
+
+        let is_hitting_horizontal_walls = self
+            .horizontal
+            .get(&y)
+            .is_some_and(
+                |v| v.iter().any(|range| range.contains(x))
+            );
+
+        let is_hitting_vertical_walls = self
+            .vertical
+            .get(&x)
+            .is_some_and(
+                |v| v.iter().any(|range| range.contains(y))
+            );
+
+        is_hitting_vertical_walls || is_hitting_horizontal_walls
+    }
+
+    fn from_rock_paths(rock_paths: &[Path]) -> Self {
+        let mut horizontal = HashMap::<i32, HashSet<Range>>::new();
+        let mut vertical = HashMap::<i32, HashSet<Range>>::new();
+
+        for path in rock_paths {
+            for i in 0..(path.len() - 1) {
+                let (curr@Point { x: cx, y: cy }, next@Point { x: nx, y: ny }) = (path[i], path[i + 1]);
+
+                // TODO: Performance improvement: connect overlapping ranges
+                if cx == nx {
+                    let range = Range::between(cy, ny);
+                    vertical
+                        .entry(cx)
+                        .and_modify(|rs| { rs.insert(range); })
+                        .or_insert(HashSet::from([range]));
+                } else if cy == ny {
+                    let range = Range::between(cx, nx);
+                    horizontal
+                        .entry(cy)
+                        .and_modify(|rs| { rs.insert(range); })
+                        .or_insert(HashSet::from([range]));
+                } else {
+                    panic!("Diagonal paths aren't supported {:?} -> {:?}", curr, next);
+                }
+            }
+        }
+
+        RockWall { vertical, horizontal }
+    }
+}
+
+/// `size` rock paths of horizontal and vertical walls below the source of the sand, the cave gets deeper
+/// with more of them so that there's room for all of the paths
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let depth = (2 * size as i64).clamp(20, 160);
+    let (left, right) = (500 - depth / 2, 500 + depth / 2);
+
+    (0..size.max(1))
+        .map(|_| {
+            let mut point = (rng.range(left..=right), rng.range(10..=depth));
+            let mut points = vec![point];
+            let horizontal = rng.chance(50);
+
+            for i in 0..rng.range(1..=4) {
+                let step = rng.range(1..=8) * if rng.chance(50) { 1 } else { -1 };
+
+                point = match (i % 2 == 0) == horizontal {
+                    true  => ((point.0 + step).clamp(left, right), point.1),
+                    false => (point.0, (point.1 + step).clamp(10, depth)),
+                };
+
+                points.push(point);
+            }
+
+            points.iter().map(|(x, y)| format!("{},{}", x, y)).collect::<Vec<String>>().join(" -> ") + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        let input = <Day as Solution>::parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input, None), "24");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = <Day as Solution>::parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input, None), "93");
+    }
+
+    #[test]
+    fn cave_snapshots() {
+        let input = <Day as Solution>::parse(EXAMPLE).unwrap();
+
+        let mut snapshot = render::Snapshot::new();
+        part1(&input, Some(&mut snapshot));
+        render::assert_snapshot("day14/snapshots/part1.txt", &snapshot);
+
+        let mut snapshot = render::Snapshot::new();
+        part2(&input, Some(&mut snapshot));
+        render::assert_snapshot("day14/snapshots/part2.txt", &snapshot);
+    }
+}
@@ -0,0 +1,285 @@
+use std::collections::HashSet;
+
+use crate::{geometry::{Point, Range}, error::{parse_number_in, ParseError}, generate::Rng, solution::{Params, Solution}};
+
+/// The furthest a sensor or a beacon can be from the origin on each axis. Their distances are up to 4 times that,
+/// so the reports are worked out with `i64`s
+const MAX_COORD: i64 = 1_000_000_000;
+
+/// Parses coordinates formatted like `x=2, y=18`, `s` has to be a slice of `input`
+fn parse_point(input: &str, s: &str) -> Result<Point<i64>, ParseError> {
+    let (x_raw, y_raw) = s
+        .split_once(',')
+        .ok_or_else(|| ParseError::at(input, s.trim(), "expected coordinates like x=2, y=18"))?;
+
+    let parse = |s: &str, pfx: &str| {
+        let raw = s
+            .trim()
+            .strip_prefix(pfx)
+            .ok_or_else(|| ParseError::at(input, s.trim(), format!("expected {}<n>", pfx)))?;
+
+        parse_number_in(input, raw, -MAX_COORD..=MAX_COORD)
+    };
+
+    let x = parse(x_raw, "x=")?;
+    let y = parse(y_raw, "y=")?;
+        
+    Ok(Point { x, y })
+}
+
+#[derive(Debug)]
+pub struct SensorReport {
+    sensor: Point<i64>,
+    beacon: Point<i64>,
+}
+
+impl SensorReport {
+    fn get_x_covered_range_for_y(&self, y: i64) -> Option<Range<i64>> {
+        let Self { sensor, beacon } = self;
+        let dist = sensor.manhattan_distance_to(beacon);
+        let y_dist = (sensor.y - y).abs();
+
+        let x_dist = dist - y_dist;
+
+        if y_dist > dist { // Out of range
+            return None;
+        }
+
+        Some(Range::new(sensor.x - x_dist, sensor.x + x_dist))
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<SensorReport>, ParseError> {
+    let parse_section = |raw: &str, pfx: &str| {
+        let coords = raw
+            .trim()
+            .strip_prefix(pfx)
+            .ok_or_else(|| ParseError::at(input, raw.trim(), format!("expected `{} x=<n>, y=<n>`", pfx)))?;
+
+        parse_point(input, coords)
+    };
+
+    input.trim()
+        .lines()
+        .map(|l| {
+            let (sensor_raw, beacon_raw) = l
+                .split_once(':')
+                .ok_or_else(|| ParseError::at(input, l, "expected a sensor and a beacon separated by a colon"))?;
+
+            let sensor = parse_section(sensor_raw, "Sensor at")?;
+            let beacon = parse_section(beacon_raw, "closest beacon is at")?;
+
+            Ok(SensorReport { sensor, beacon })
+        })
+        .collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<SensorReport>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> String {
+        let row = params.row.unwrap_or(if params.example { 10 } else { 2000000 });
+
+        part1(input, row)
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> String {
+        let bound = params.bound.unwrap_or(if params.example { 20 } else { 4000000 });
+
+        part2(input, bound)
+    }
+}
+
+fn part1(reports: &[SensorReport], row: i32) -> String {
+    let row = row as i64;
+
+    let ranges: Vec<Range<i64>> = Range::merge(
+        &mut reports
+            .iter()
+            .filter_map(|sr| sr.get_x_covered_range_for_y(row))
+            .collect::<Vec<Range<i64>>>()
+    );
+
+    let beacons_present = HashSet::<Point<i64>>::from_iter(reports.iter().map(|x| x.beacon))
+        .iter()
+        .filter(|Point { x, y }| {
+            if *y != row {
+                return false;
+            }
+
+            ranges.iter().any(|range| range.contains(*x))
+        }).count();
+
+    let positions_count: i64 = ranges
+        .iter()
+        .map(|range| range.len())
+        .sum();
+    
+    let result = positions_count - (beacons_present as i64);
+
+    result.to_string()
+}
+
+fn part2(reports: &[SensorReport], bound: i32) -> String {
+    let mut beacon = None;
+
+    for y in 0..bound as i64 {
+        let ranges: Vec<Range<i64>> = Range::merge(
+            &mut reports
+                .iter()
+                .filter_map(|sr| sr.get_x_covered_range_for_y(y))
+                .collect::<Vec<Range<i64>>>()
+        );
+
+        for i in 0..ranges.len().saturating_sub(1) {
+            let (curr, next) = (ranges[i], ranges[i + 1]);
+            
+            for x in (curr.end + 1)..next.start {
+                // Stops at the second hole, the gaps between the ranges can be huge when the sensors don't cover the area
+                if beacon.is_some() {
+                    return String::from("Found multiple distress beacons");
+                }
+
+                beacon = Some(Point { x, y });
+            }
+        }
+    }
+
+    let Some(beacon) = beacon else {
+        return String::from("Not found");
+    };
+
+    // The hole can be left of the area when the sensors don't cover it, so it's kept signed
+    let result = beacon.x * 4000000 + beacon.y;
+
+    result.to_string()
+}
+
+/// Sensors that leave exactly one cell uncovered between 0 and `size` (4 to 50000000), run part 2 with `--bound <size>`.
+/// Four big sensors surround the hole from each side, with more of the small ones scattered around it
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let bound = size.clamp(4, 50_000_000) as i64;
+    let hole = (rng.range(0..=bound - 1), rng.range(0..=bound - 1));
+
+    // The sensors cover diamonds, which are squares in these coordinates
+    let (hu, hv) = (hole.0 + hole.1, hole.0 - hole.1);
+    let radius = 6 * bound + 6;
+
+    // Each of them stops right before the hole, the centres need an even u + v to be whole points
+    let big = [(hu - 1 - radius, hv), (hu + 1 + radius, hv), (hu, hv - 1 - radius), (hu, hv + 1 + radius)]
+        .map(|(u, v)| match u == hu {
+            true  => (u + (v - u).rem_euclid(2), v),
+            false => (u, v + (u - v).rem_euclid(2)),
+        })
+        .map(|(u, v)| ((u + v) / 2, (u - v) / 2, radius));
+
+    let small = (0..20).filter_map(|_| {
+        let sensor = (rng.range(0..=bound - 1), rng.range(0..=bound - 1));
+        let distance = (sensor.0 - hole.0).abs() + (sensor.1 - hole.1).abs();
+
+        (distance > 0).then(|| (sensor.0, sensor.1, rng.range(0..=distance - 1)))
+    });
+
+    let mut sensors: Vec<(i64, i64, i64)> = big.into_iter().chain(small).collect();
+    rng.shuffle(&mut sensors);
+
+    sensors.iter()
+        .map(|&(x, y, distance)| {
+            let dx = rng.range(-distance..=distance);
+            let dy = (distance - dx.abs()) * if rng.chance(50) { 1 } else { -1 };
+
+            format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", x, y, x + dx, y + dy)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use crate::generate;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input, 10), "26");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input, 20), "56000011");
+    }
+
+    #[test]
+    fn far_away_sensors_dont_overflow() {
+        let input = parse("Sensor at x=1000000000, y=0: closest beacon is at x=-1000000000, y=-1000000000\n").unwrap();
+        assert_eq!(part1(&input, i32::MAX), "1705032707");
+
+        let err = parse("Sensor at x=2147483647, y=0: closest beacon is at x=-2147483648, y=0\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (1, 13, "2147483647"));
+        assert_eq!(err.message, "expected a number from -1000000000 to 1000000000");
+    }
+
+    fn is_covered(reports: &[SensorReport], point: &Point<i64>) -> bool {
+        reports.iter().any(|sr| sr.sensor.manhattan_distance_to(point) <= sr.sensor.manhattan_distance_to(&sr.beacon))
+    }
+
+    /// Checks every cell of the row that any sensor could reach
+    fn part1_brute_force(reports: &[SensorReport], row: i32) -> usize {
+        let reach = |sr: &SensorReport| sr.sensor.manhattan_distance_to(&sr.beacon);
+        let min_x = reports.iter().map(|sr| sr.sensor.x - reach(sr)).min().unwrap_or(0);
+        let max_x = reports.iter().map(|sr| sr.sensor.x + reach(sr)).max().unwrap_or(0);
+
+        (min_x..=max_x)
+            .map(|x| Point::new(x, row as i64))
+            .filter(|point| is_covered(reports, point) && ! reports.iter().any(|sr| sr.beacon == *point))
+            .count()
+    }
+
+    /// Checks every cell of the area
+    fn part2_brute_force(reports: &[SensorReport], bound: i32) -> Vec<Point<i64>> {
+        let bound = bound as i64;
+
+        (0..bound)
+            .flat_map(|y| (0..bound).map(move |x| Point::new(x, y)))
+            .filter(|point| ! is_covered(reports, point))
+            .collect()
+    }
+
+    fn report() -> impl Strategy<Value = SensorReport> {
+        (-20..=20i64, -20..=20i64, -8..=8i64, -8..=8i64)
+            .prop_map(|(x, y, dx, dy)| SensorReport { sensor: Point::new(x, y), beacon: Point::new(x + dx, y + dy) })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn part1_matches_brute_force(reports in vec(report(), 1..8), row in -25..=25) {
+            prop_assert_eq!(part1(&reports, row), part1_brute_force(&reports, row).to_string());
+        }
+
+        // Part 2 expects a single uncovered cell, which the generated inputs always have
+        #[test]
+        fn part2_matches_brute_force(seed in any::<u64>(), bound in 4..=30usize) {
+            let reports = parse(&generate(&mut generate::Rng::new(seed), bound)).unwrap();
+            let holes = part2_brute_force(&reports, bound as i32);
+
+            prop_assert_eq!(holes.len(), 1);
+            prop_assert_eq!(part2(&reports, bound as i32), (holes[0].x * 4000000 + holes[0].y).to_string());
+        }
+    }
+}
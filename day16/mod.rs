@@ -0,0 +1,553 @@
+use std::{str::FromStr, collections::HashMap};
+
+use crate::{error::{parse_number_in, ParseError}, generate::Rng, solution::{Params, Solution}};
+
+/// The highest flow rate of a valve, so that all 63 of them flowing for 30 minutes still fit in a `u32`
+const MAX_RATE: u32 = 1_000_000;
+
+#[derive(Debug, Clone)]
+struct Valve {
+    name: String,
+    rate: u32,
+    connections: Vec<String>,
+}
+
+impl FromStr for Valve {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, b) = s
+            .trim()
+            .split_once(';')
+            .ok_or_else(|| ParseError::at(s, s.trim(), "expected a valve and its tunnels separated by a semicolon"))?;
+
+        let (raw_name, raw_rate) = a
+            .trim()
+            .split_once(" has flow ")
+            .ok_or_else(|| ParseError::at(s, a.trim(), "expected `Valve <name> has flow rate=<n>`"))?;
+
+        let name = raw_name
+            .trim()
+            .strip_prefix("Valve ").map(String::from)
+            .ok_or_else(|| ParseError::at(s, raw_name.trim(), "expected `Valve <name>`"))?;
+
+        let raw_rate = raw_rate
+            .trim()
+            .strip_prefix("rate=")
+            .ok_or_else(|| ParseError::at(s, raw_rate.trim(), "expected `rate=<n>`"))?;
+
+        let rate = parse_number_in(s, raw_rate, 0..=MAX_RATE)?;
+
+        let connections = Some(b.trim())
+            .and_then(|s| s.strip_prefix("tunnel ").or(s.strip_prefix("tunnels ")))
+            .and_then(|s| s.strip_prefix("lead ").or(s.strip_prefix("leads ")))
+            .and_then(|x| x.strip_prefix("to "))
+            .and_then(|s| s.strip_prefix("valve ").or(s.strip_prefix("valves "))).map(|s| s.split(',')
+                .map(|c| c.trim().to_string())
+                .collect::<Vec<String>>())
+            .ok_or_else(|| ParseError::at(s, b.trim(), "expected `tunnels lead to valves <names>`"))?;
+
+        Ok(Valve { name, rate, connections })
+    }
+}
+
+#[derive(Debug)]
+pub struct SimpleValve {
+    name: String,
+    rate: u32,
+    links: Vec<usize>,
+}
+
+fn parse(input: &str) -> Result<Vec<SimpleValve>, ParseError> {
+    let lines: Vec<&str> = input.lines()
+        .filter(|l| ! l.trim().is_empty())
+        .collect();
+
+    let valves: Vec<Valve> = lines.iter()
+        .map(|l| l.parse::<Valve>().map_err(|err| err.within(input, l)))
+        .collect::<Result<_, _>>()?;
+
+    // Which valves are still closed is kept in the bits of a u64
+    if let Some(line) = lines.get(63) {
+        return Err(ParseError::at(input, line, "there can't be more than 63 valves"));
+    }
+    
+    let idx_map: HashMap<String, usize> = valves.iter()
+        .enumerate()
+        .fold(HashMap::new(), |mut m, (i, x)| {
+            m.insert(x.name.clone(), i);
+            m
+        });
+
+    if ! idx_map.contains_key("AA") {
+        return Err(ParseError::end_of_input(input, "the starting valve AA was not found"));
+    }
+
+    valves.into_iter()
+        .zip(lines)
+        .map(|(v, l)| {
+            let links = v.connections.iter().map(|x| {
+                idx_map.get(x).copied().ok_or_else(|| {
+                    // The tunnels are listed at the end of the line
+                    let text = l.rmatch_indices(x.as_str()).next().map_or(l, |(i, m)| &l[i..(i + m.len())]);
+
+                    ParseError::at(input, text, format!("tunnel leads to an unknown valve {}", x))
+                })
+            }).collect::<Result<_, _>>()?;
+
+            Ok(SimpleValve {
+                name: v.name,
+                rate: v.rate,
+                links,
+            })
+        })
+        .collect()
+}
+
+/*
+ * Convert the valves into a weighted graph and generate a min distance matrix by using floyd warshall
+ *
+ *
+ * How we keep track of open valves
+ * Let's say that we have a list of 8 valves, and the starting valve is at index 3
+ * The starting bitmask in this case would look like this
+ * 0b11110111
+ *
+ * Open valves are represented by 0s and closed ones are 1s
+ *
+ * the size of the bitmask is based on how many valves we want to keep track of
+ * in our case the size is 2^8 - 1
+ * 2^8     = 0b100000000
+ * 2^8 - 1 = 0b011111111
+ *
+ * As perform the simulation we modify the bitmask according to what valves are opened, and we
+ * keep track of the highest possible flow rate out of each path combination
+ * We also use a hashmap that keeps track the highest flow rate that we can get out of
+ * different valve combinations (This will be important for part2)
+ *
+ * # Part 1
+ *
+ * This part is pretty straightforward, we can just use the highest flow rate returned by the
+ * simulation 
+ * 
+ *
+ * # Part 2
+ *
+ * Here, we simulate the paths of the elf and the elpehant separately which results in 2 path
+ * hashmaps 
+ *
+ * After that we loop over each of the elf's paths and find paths that the elephant took
+ * that don't overlap (using some bitmasks magic) with the elf path being checked;
+ * Overlap here means that both of them opened the same valves, so we basically discard all
+ * paths where both of them have at least 1 valve in common because a valve can only be opened
+ * once.
+ *
+ * (! elf_mask) & (! elephant_mask) & init_mask == 0
+ * The reason why we flip elf_mask's and elephant_mask's bits is because open valves are represented by 0s
+ * instead of 1s (not sure why I thought this was a good idea) and then we have to AND the result
+ * with the initial mask because when we flip the bits of a number, all of the bits are flipped (depending on the integer size being used)
+ * and not only the ones being used, for example if we have 0b0011 that is stored within a u16 and
+ * we flip the bits we'll end up with 0b1111111111111100, as you can see the flipping will happen
+ * across all 16 bits, and that's why we AND the result with the initial_mask.
+ *
+ * If the result of the above bitmask expression results in a number that's bigger than 0 that
+ * means that we have overlaps and we have to skip the current pair.
+ * For pairs that don't have overlaps we add their highest flow rates together and compare them
+ * with the rest.
+ */
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<SimpleValve>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(input)
+    }
+}
+
+fn part1(valves: &[SimpleValve]) -> String {
+    let graph = init_graph(valves, |x| &x.links);
+    let dist = floyd_warshall(graph);
+
+    let start_idx = valves.iter().position(|x| x.name == "AA").unwrap();
+    let len = dist.len();
+
+    // I spent 20 hours trying to find a bug that later appeared to be happening because of this line
+    // APPARENTLY THE STARTING VALVE IS ALWAYS "AA" AND NOT THE FIRST VALVE IN THE INPUT
+    let init_mask: u64 = (1 << len) - 1;
+
+    let (flow, _) = simulate(valves, &dist, init_mask, start_idx, 30);
+
+    flow.to_string()
+}
+
+fn part2(valves: &[SimpleValve]) -> String {
+    let graph = init_graph(valves, |x| &x.links);
+    let dist = floyd_warshall(graph);
+
+    let start_idx = valves.iter().position(|x| x.name == "AA").unwrap();
+    let init_mask: u64 = (1 << dist.len()) - 1;
+
+    let (_, elf_memo) = simulate(valves, &dist, init_mask, start_idx, 26);
+    let (_, elephant_memo) = simulate(valves, &dist, init_mask, start_idx, 26);
+
+    let max_flow = elf_memo
+        .iter()
+        .fold(0, |max, (&elf_mask, &elf_flow)| {
+            elephant_memo.iter()
+                .fold(max, |max, (&mask, &elephant_flow)| {
+                    // Check that there's no overlap between the 2 paths
+                    if (! mask) & (! elf_mask) & init_mask == 0 {
+                        return max.max(elephant_flow + elf_flow);
+                    }
+
+                    max
+                })
+        });
+
+
+    max_flow.to_string()
+}
+
+fn simulate(
+    valves: &[SimpleValve],
+    dist: &[Vec<u32>],
+    init_mask: u64,
+    start_idx: usize,
+    minutes: u32,
+) -> (u32, HashMap<u64, u32>) {
+    let non_zero_valves: Vec<usize> = valves
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| x.rate > 0)
+        .map(|(i, _)| i)
+        .collect();
+
+    let flow = 0;
+    let mut mask_flow: HashMap<u64, u32> = HashMap::new();
+
+    let flow = traveling_salesman(valves, &mut mask_flow, &non_zero_valves, dist, init_mask, minutes, flow, start_idx);
+
+    (flow, mask_flow)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn traveling_salesman(
+    valves: &[SimpleValve],
+    memo: &mut HashMap<u64, u32>,
+    non_zero_valves: &[usize],
+    dist: &[Vec<u32>],
+    mask: u64,
+    minutes: u32,
+    flow: u32,
+    i: usize,
+) -> u32 {
+
+    let mut max_flow = flow;
+
+    memo.insert(mask, *memo.get(&mask).unwrap_or(&0).max(&flow));
+
+    for &j in non_zero_valves.iter() {
+        let cur_minutes = minutes
+            .checked_sub(dist[i][j])
+            .and_then(|x| x.checked_sub(1))
+            .unwrap_or(0);
+
+        if (mask & (1 << j)) == 0 || cur_minutes == 0 {
+            continue;
+        }
+
+        let cur_mask = mask & ! (1 << j);
+
+        let cur_flow = flow + (cur_minutes * valves[j].rate);
+
+        max_flow = max_flow.max(
+            traveling_salesman(valves, memo, non_zero_valves, dist, cur_mask, cur_minutes, cur_flow, j)
+        );
+    }
+
+    max_flow
+}
+
+fn floyd_warshall(graph: Vec<Vec<u32>>) -> Vec<Vec<u32>> {
+    let l = graph.len();
+    let mut dist = graph.clone();
+
+    for k in 0..l {
+        for i in 0..l {
+            for j in 0..l {
+                if dist[i][k] + dist[k][j] < dist[i][j] {
+                    dist[i][j] = dist[i][k] + dist[k][j];
+                }
+            }
+        }
+    }
+
+    dist
+}
+
+fn init_graph<T, F>(list: &[T], get_links: F) -> Vec<Vec<u32>>
+    where F: Fn(&T) -> &Vec<usize> {
+    let l = list.len();
+    let mut graph = vec![vec![u32::MAX / 4; l]; l];
+
+    list
+        .iter()
+        .enumerate()
+        .for_each(|(i, x)| {
+            get_links(x).iter().for_each(|&j| graph[i][j] = 1);
+        });
+
+    graph
+}
+
+/// `size` valves with a flow rate (1 to 25) and as many broken ones, all connected to each other and to AA
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let flowing = size.clamp(1, 25);
+
+    let mut names: Vec<String> = ('A'..='Z')
+        .flat_map(|a| ('A'..='Z').map(move |b| format!("{}{}", a, b)))
+        .filter(|name| name != "AA")
+        .collect();
+    rng.shuffle(&mut names);
+
+    names.truncate(2 * flowing);
+    names.insert(0, String::from("AA"));
+
+    let rates: Vec<i64> = (0..names.len()).map(|i| if i > 0 && i <= flowing { rng.range(1..=25) } else { 0 }).collect();
+    let mut links: Vec<Vec<usize>> = vec![Vec::new(); names.len()];
+
+    // A random tree so that every valve can be reached, and a few more tunnels to make loops
+    let mut order: Vec<usize> = (0..names.len()).collect();
+    rng.shuffle(&mut order);
+
+    let tree: Vec<(usize, usize)> = (1..order.len()).map(|i| (order[i], order[rng.below(i)])).collect();
+    let extra: Vec<(usize, usize)> = (0..flowing / 2).map(|_| (rng.below(names.len()), rng.below(names.len()))).collect();
+
+    for (a, b) in tree.into_iter().chain(extra) {
+        if a != b && ! links[a].contains(&b) {
+            links[a].push(b);
+            links[b].push(a);
+        }
+    }
+
+    let mut lines: Vec<String> = (0..names.len())
+        .map(|i| {
+            let tunnels: Vec<&str> = links[i].iter().map(|&j| names[j].as_str()).collect();
+            let lead = if tunnels.len() == 1 { "tunnel leads to valve" } else { "tunnels lead to valves" };
+
+            format!("Valve {} has flow rate={}; {} {}\n", names[i], rates[i], lead, tunnels.join(", "))
+        })
+        .collect();
+    rng.shuffle(&mut lines);
+
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    const FAR: u32 = u32::MAX / 4; // What init_graph uses for missing links
+
+    #[test]
+    fn example_part1() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), "1651");
+    }
+
+    #[test]
+    fn example_part2() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), "1707");
+    }
+
+    #[test]
+    fn parse_rejects_flow_rates_that_are_too_high() {
+        let err = parse("Valve AA has flow rate=0; tunnel leads to valve BB\nValve BB has flow rate=4294967295; tunnel leads to valve AA\n").unwrap_err();
+
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 24, "4294967295"));
+        assert_eq!(err.message, "expected a number from 0 to 1000000");
+
+        let input = parse("Valve AA has flow rate=0; tunnel leads to valve BB\nValve BB has flow rate=1000000; tunnel leads to valve AA\n").unwrap();
+        assert_eq!(part1(&input), "28000000");
+    }
+
+    #[test]
+    fn floyd_warshall_finds_the_shortest_paths() {
+        // 0 - 1 - 2 - 3
+        //  \_______/
+        let links: Vec<Vec<usize>> = vec![vec![1, 2], vec![0, 2], vec![1, 3, 0], vec![2]];
+
+        let dist = floyd_warshall(init_graph(&links, |x| x));
+
+        assert_eq!(dist[0][1], 1);
+        assert_eq!(dist[0][2], 1);
+        assert_eq!(dist[0][3], 2);
+        assert_eq!(dist[3][0], 2);
+        assert_eq!(dist[1][3], 2);
+    }
+
+    #[test]
+    fn floyd_warshall_follows_one_way_links() {
+        // 0 -> 1 -> 2
+        let links: Vec<Vec<usize>> = vec![vec![1], vec![2], vec![]];
+
+        let dist = floyd_warshall(init_graph(&links, |x| x));
+
+        assert_eq!(dist[0][2], 2);
+        assert_eq!(dist[2][0], FAR);
+    }
+
+    #[test]
+    fn floyd_warshall_leaves_unreachable_valves_far_away() {
+        let links: Vec<Vec<usize>> = vec![vec![1], vec![0], vec![]];
+
+        let dist = floyd_warshall(init_graph(&links, |x| x));
+
+        assert_eq!(dist[0][1], 1);
+        assert_eq!(dist[0][2], FAR);
+        assert_eq!(dist[2][1], FAR);
+    }
+
+    #[test]
+    fn floyd_warshall_on_the_example() {
+        let valves = parse(EXAMPLE).unwrap();
+        let idx = |name: &str| valves.iter().position(|v| v.name == name).unwrap();
+
+        let dist = floyd_warshall(init_graph(&valves, |v| &v.links));
+
+        assert_eq!(dist[idx("AA")][idx("DD")], 1);
+        assert_eq!(dist[idx("AA")][idx("JJ")], 2);
+        assert_eq!(dist[idx("AA")][idx("HH")], 5);
+        assert_eq!(dist[idx("HH")][idx("JJ")], 7);
+    }
+
+    /// The most pressure that can be released from here, trying every move of every minute
+    fn part1_brute_force(valves: &[SimpleValve], memo: &mut HashMap<(usize, u32, u64), u32>, at: usize, minutes: u32, open: u64) -> u32 {
+        if minutes == 0 {
+            return 0;
+        }
+
+        if let Some(&flow) = memo.get(&(at, minutes, open)) {
+            return flow;
+        }
+
+        let mut best = 0;
+
+        if valves[at].rate > 0 && open & (1 << at) == 0 {
+            best = (minutes - 1) * valves[at].rate + part1_brute_force(valves, memo, at, minutes - 1, open | (1 << at));
+        }
+
+        for &next in &valves[at].links {
+            best = best.max(part1_brute_force(valves, memo, next, minutes - 1, open));
+        }
+
+        memo.insert((at, minutes, open), best);
+        best
+    }
+
+    /// Same as above with the elephant, both of them either open their valve, follow a tunnel or wait each minute
+    fn part2_brute_force(valves: &[SimpleValve], memo: &mut HashMap<(usize, usize, u32, u64), u32>, at: [usize; 2], minutes: u32, open: u64) -> u32 {
+        if minutes == 0 {
+            return 0;
+        }
+
+        // Who is where doesn't matter
+        let at = [at[0].min(at[1]), at[0].max(at[1])];
+
+        if let Some(&flow) = memo.get(&(at[0], at[1], minutes, open)) {
+            return flow;
+        }
+
+        // Where each of them can go, along with the valve that they open
+        let moves = |at: usize| {
+            let opens = (valves[at].rate > 0 && open & (1 << at) == 0).then_some((at, Some(at)));
+
+            opens.into_iter()
+                .chain(valves[at].links.iter().map(|&next| (next, None)))
+                .chain([(at, None)])
+                .collect::<Vec<_>>()
+        };
+
+        let mut best = 0;
+
+        for (elf, elf_opens) in moves(at[0]) {
+            for (elephant, elephant_opens) in moves(at[1]) {
+                if elf_opens.is_some() && elf_opens == elephant_opens {
+                    continue;
+                }
+
+                let opened: Vec<usize> = elf_opens.into_iter().chain(elephant_opens).collect();
+                let flow: u32 = opened.iter().map(|&valve| (minutes - 1) * valves[valve].rate).sum();
+                let open = opened.iter().fold(open, |open, &valve| open | (1 << valve));
+
+                best = best.max(flow + part2_brute_force(valves, memo, [elf, elephant], minutes - 1, open));
+            }
+        }
+
+        memo.insert((at[0], at[1], minutes, open), best);
+        best
+    }
+
+    /// Up to 5 valves with random tunnels between them, AA doesn't have a flow rate and isn't always the first one
+    fn tunnels() -> impl Strategy<Value = Vec<SimpleValve>> {
+        (2..=5usize)
+            .prop_flat_map(|n| (vec(0..=20u32, n), vec((0..n, 0..n), 0..=(2 * n)), 0..n))
+            .prop_map(|(rates, tunnels, start)| {
+                let mut valves: Vec<SimpleValve> = rates.iter()
+                    .enumerate()
+                    .map(|(i, &rate)| SimpleValve { name: format!("V{}", i), rate, links: vec![] })
+                    .collect();
+
+                valves[start].name = String::from("AA");
+                valves[start].rate = 0;
+
+                for (a, b) in tunnels {
+                    if a != b && ! valves[a].links.contains(&b) {
+                        valves[a].links.push(b);
+                        valves[b].links.push(a);
+                    }
+                }
+
+                valves
+            })
+    }
+
+    fn start(valves: &[SimpleValve]) -> usize {
+        valves.iter().position(|v| v.name == "AA").unwrap()
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1000))]
+
+        #[test]
+        fn part1_matches_brute_force(valves in tunnels()) {
+            let expected = part1_brute_force(&valves, &mut HashMap::new(), start(&valves), 30, 0);
+
+            prop_assert_eq!(part1(&valves), expected.to_string());
+        }
+    }
+
+    proptest! {
+        // Both of them trying everything each minute is a lot slower
+        #![proptest_config(ProptestConfig::with_cases(300))]
+
+        #[test]
+        fn part2_matches_brute_force(valves in tunnels()) {
+            let expected = part2_brute_force(&valves, &mut HashMap::new(), [start(&valves); 2], 26, 0);
+
+            prop_assert_eq!(part2(&valves), expected.to_string());
+        }
+    }
+}
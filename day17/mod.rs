@@ -0,0 +1,589 @@
+use std::{collections::{HashSet, HashMap}, cmp, time::Duration};
+
+use crate::{geometry, error::ParseError, generate::Rng, render::{self, Color, Frame, Rect, Renderer, Scene, Style}, solution::{Params, Solution}};
+
+type Point = geometry::Point<i64>;
+
+
+#[derive(Debug, Clone)]
+struct RockCluster {
+    points: Vec<Point>,
+}
+
+impl RockCluster {
+    fn get_rock_clusters() -> Vec<Self> {
+        vec![
+            // Horizontal Line
+            Self { points: vec![Point::new(0, 0), Point::new(1, 0), Point::new(2, 0), Point::new(3, 0)] },
+            // Plus sign
+            Self { points: vec![Point::new(1, 0), Point::new(1, 1), Point::new(0, 1), Point::new(2, 1), Point::new(1, 2)] },
+            // Reversed L
+            Self { points: vec![Point::new(2, 0), Point::new(2, 1), Point::new(2, 2), Point::new(1, 0), Point::new(0, 0)] }, 
+            // Vertical Line
+            Self { points: vec![Point::new(0, 0), Point::new(0, 1), Point::new(0, 2), Point::new(0, 3)] },
+            // Cube
+            Self { points: vec![Point::new(1, 0), Point::new(1, 1), Point::new(0, 0), Point::new(0, 1)] },
+        ]
+    }
+
+    fn move_by(&self, delta: Point) -> Self {
+        let points = self.points
+            .iter()
+            .map(|&p| p + delta)
+            .collect();
+
+        RockCluster { points }
+    }
+}
+
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Direction {
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    fn to_move_delta(&self) -> Point {
+        match self {
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, -1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Chamber {
+    rested: HashMap<i64, HashSet<Point>>,
+    width: u64,
+    height_reached: u64,
+    /// The lowest row, part 2 moves everything up when it skips the cycles
+    bottom: i64,
+}
+
+impl Chamber {
+    fn move_rock_dir(&self, rock: &RockCluster, dir: &Direction) -> Option<RockCluster> {
+        let new_rock = rock.move_by(dir.to_move_delta());
+        
+        let mut is_colliding_with_rested_rocks = false;
+        
+        for point in new_rock.points.iter() {
+            let row = self.rested.get(&point.y);
+
+            match row {
+                Some(set)
+                    if set.contains(point) => {
+                        is_colliding_with_rested_rocks = true;
+                        break;
+                    },
+                _ => (),
+            }
+        }
+
+        let is_colliding_with_walls = new_rock.points.iter().any(|p| p.x < 0 || (self.width as i64) <= p.x);
+        let is_colliding_with_floor = new_rock.points.iter().any(|p| p.y < 0);
+
+        if is_colliding_with_floor || is_colliding_with_walls || is_colliding_with_rested_rocks {
+            return None;
+        }
+
+        Some(new_rock)
+    }
+
+    /// The empty cells that a falling rock could still get to, as a row of bits per row from the top of the tower down
+    /// to `SURFACE_DEPTH`. Nothing else changes where the rocks land as long as they stay above that, or if none of
+    /// the bottom row can be reached, so towers with the same of them grow the same way
+    fn surface(&self) -> Vec<u8> {
+        let top = self.height_reached as i64;
+        let full = (1u8 << self.width) - 1;
+
+        // The top row is above the tower, so it's always empty
+        let free: Vec<u8> = (0..=SURFACE_DEPTH)
+            .map(|i| match self.rested.get(&(top - i)) {
+                _ if top - i < 0 => 0,
+                Some(row) => full & ! row.iter().fold(0, |bits, p| bits | (1 << p.x)),
+                None => full,
+            })
+            .collect();
+
+        let mut reachable = vec![0u8; free.len()];
+        reachable[0] = full;
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+
+            for i in 1..free.len() {
+                let mut row = reachable[i] | ((reachable[i - 1] | reachable.get(i + 1).copied().unwrap_or(0)) & free[i]);
+
+                // Sideways, until it runs into the rocks
+                loop {
+                    let spread = (row | (row << 1) | (row >> 1)) & free[i];
+
+                    if spread == row {
+                        break;
+                    }
+
+                    row = spread;
+                }
+
+                if row != reachable[i] {
+                    reachable[i] = row;
+                    changed = true;
+                }
+            }
+        }
+
+        reachable
+    }
+
+    fn rest_rock_cluster(&mut self, rock: RockCluster) {
+        self.height_reached = self.height_reached.max(
+            rock.points
+                .iter()
+                .map(|p| p.y + 1)
+                .max()
+                .unwrap_or(0) as u64
+        );
+
+        rock
+            .points
+            .iter()
+            .for_each(|p| {
+                let set = self.rested.get_mut(&p.y);
+
+                match set {
+                    Some(data) => {
+                        data.insert(*p);
+                    },
+                    None => {
+                        self.rested.insert(p.y, HashSet::from_iter([*p]));
+                    },
+                };
+            });
+
+    }
+
+    fn simulate_rock_fall(
+        &mut self,
+        gas_jets: &[Direction],
+        gas_jet_idx: &mut usize,
+        rock: &RockCluster,
+        mut renderer: Option<&mut (dyn Renderer + '_)>,
+    ) -> RockCluster {
+        let mut rock = rock.clone();
+
+        let mut down = false;
+
+        loop {
+            let jet = &gas_jets[*gas_jet_idx % gas_jets.len()];
+            let dir = if down { &Direction::Down } else { jet };
+
+            let moved_rock = self.move_rock_dir(&rock, dir);
+            
+            if let Some(renderer) = renderer.as_mut() {
+                let jet_idx = *gas_jet_idx % gas_jets.len();
+
+                renderer.draw_scene(&Scene {
+                    world: tower_world(self, gas_jets),
+                    focus: to_world(self.bottom, &rock.points[0]),
+                    draw: &|view| Some(tower_frame(self, gas_jets, jet_idx, Some(&rock), view)),
+                });
+            }
+
+            if down {
+                match moved_rock {
+                    Some(new_rock) => {
+                        rock = new_rock;
+                    },
+                    None => {
+                        self.rest_rock_cluster(rock.clone());
+                        break;
+                    },
+                }
+            } else {
+                *gas_jet_idx += 1;
+
+                if let Some(new_rock) = moved_rock {
+                    rock = new_rock;
+                }
+            }
+
+            down = ! down;
+        }
+
+        rock
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Direction>, ParseError> {
+    let trimmed = input.trim();
+
+    if trimmed.is_empty() {
+        return Err(ParseError::end_of_input(input, "expected at least one jet"));
+    }
+
+    trimmed
+        .char_indices()
+        .map(|(i, c)| {
+            match c {
+                '<' => Ok(Direction::Left),
+                '>' => Ok(Direction::Right),
+                _   => Err(ParseError::at(input, &trimmed[i..(i + c.len_utf8())], "invalid jet, expected < or >")),
+            }
+        })
+        .collect()
+}
+
+/// The chamber, with the height and the gas jets next to it
+const WORLD_WIDTH: usize = 80;
+
+/// Room above the tower for the next rock to fall from
+const HEADROOM: i64 = 7;
+
+/// How far below the top of the tower the cycles of part 2 look for the rocks to land
+const SURFACE_DEPTH: i64 = 64;
+
+const JETS_LEFT: i32 = 10;
+const JETS_TOP: i32 = 2;
+const JETS_PER_ROW: usize = WORLD_WIDTH - JETS_LEFT as usize - 3;
+
+/// Where a point of the chamber ends up in the world, the y axis points down with the floor below 0
+fn to_world(bottom: i64, &Point { x, y }: &Point) -> geometry::Point {
+    geometry::Point::new(x as i32 + 1, (bottom - y) as i32)
+}
+
+fn tower_world(chamber: &Chamber, gas_jets: &[Direction]) -> Rect {
+    let top = to_world(chamber.bottom, &Point::new(0, chamber.height_reached as i64 + HEADROOM)).y;
+    let jet_rows = JETS_TOP as usize + gas_jets.len().div_ceil(JETS_PER_ROW);
+
+    // Down to the floor, which is at 1
+    Rect::new(geometry::Point::new(0, top), WORLD_WIDTH, cmp::max((2 - top) as usize, jet_rows))
+}
+
+/// The part of the chamber inside of the view, with the height reached and the gas jets
+/// in the top rows of the world and the current jet in green
+fn tower_frame(
+    chamber: &Chamber,
+    gas_jets: &[Direction],
+    gas_jet_idx: usize,
+    falling_rock: Option<&RockCluster>,
+    view: Rect,
+) -> Frame {
+    let mut frame = view.frame();
+    let world = tower_world(chamber, gas_jets);
+    let bottom = chamber.bottom;
+    let put = |frame: &mut Frame, point: geometry::Point, ch: char| frame.put(view.to_frame(point), ch);
+
+    let chamber_draw_width = (chamber.width + 1) as i32;
+    let rows = view.min.y..(view.min.y + view.height as i32);
+
+    for y in rows.clone() {
+        // Draw walls
+        if y < 1 {
+            put(&mut frame, geometry::Point::new(0, y), '|');
+            put(&mut frame, geometry::Point::new(chamber_draw_width, y), '|');
+        }
+
+        // Draw rested rocks
+        for point in chamber.rested.get(&(bottom - y as i64)).into_iter().flatten() {
+            put(&mut frame, to_world(bottom, point), '#');
+        }
+    }
+
+    // Draw floor
+    for i in 0..=chamber_draw_width {
+        if i == 0 || i == chamber_draw_width {
+            put(&mut frame, geometry::Point::new(i, 1), '+');
+        } else {
+            put(&mut frame, geometry::Point::new(i, 1), '-');
+        }
+    }
+
+    // Draw falling rock
+    for point in falling_rock.iter().flat_map(|rock| rock.points.iter()) {
+        put(&mut frame, to_world(bottom, point), '@');
+    }
+
+    let get_jet_char = |jet: &Direction| {
+        match jet {
+            Direction::Left => '<',
+            Direction::Right => '>',
+            _ => panic!("Unsupported jet"),
+        }
+    };
+
+    // Draw reached height
+    frame.text(
+        view.to_frame(geometry::Point::new(JETS_LEFT, world.min.y)),
+        &format!("Reached height: {}", chamber.height_reached),
+    );
+
+    // Draw Jets, the rows that are out of view are left out
+    for (i, jet) in gas_jets.iter().enumerate() {
+        let y = (i / JETS_PER_ROW) as i32 + JETS_TOP + world.min.y;
+        let x = (i % JETS_PER_ROW) as i32 + JETS_LEFT;
+
+        if ! rows.contains(&y) {
+            continue;
+        }
+
+        let point = view.to_frame(geometry::Point::new(x, y));
+
+        if i == gas_jet_idx {
+            frame.put_styled(point, get_jet_char(jet), Style::new(Color::Black, Color::Green));
+        } else {
+            frame.put(point, get_jet_char(jet));
+        }
+    }
+
+    frame
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Direction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, params: &Params) -> String {
+        let mut renderer = render::renderer(params, Duration::ZERO);
+
+        part1(input, renderer.as_deref_mut())
+    }
+
+    fn part2(input: &Self::Input, params: &Params) -> String {
+        let mut renderer = render::renderer(params, Duration::ZERO);
+
+        part2(input, renderer.as_deref_mut())
+    }
+}
+
+fn part1(gas_jets: &[Direction], renderer: Option<&mut (dyn Renderer + '_)>) -> String {
+    let result = simulate(gas_jets, 2022, renderer);
+
+    result.to_string()
+}
+
+
+fn part2(gas_jets: &[Direction], renderer: Option<&mut (dyn Renderer + '_)>) -> String {
+    let result = simulate(gas_jets, 1000000000000, renderer);
+
+    result.to_string()
+}
+
+
+fn simulate(gas_jets: &[Direction], rocks_max: usize, mut renderer: Option<&mut (dyn Renderer + '_)>) -> u64 {
+    let rock_clusters = RockCluster::get_rock_clusters();
+    let mut gas_jet_idx = 0;
+
+    let mut chamber = Chamber {
+        rested: HashMap::new(),
+        width: 7,
+        height_reached: 0,
+        bottom: 0,
+    };
+
+    // The height and the number of rocks the last time each rock fell with the same jet onto the same tower top,
+    // the rocks in between repeat from then on if the surface is closed off or none of them fell below it
+    let mut seen = HashMap::<(usize, usize, Vec<u8>), (u64, usize)>::new();
+    let mut skipped = false;
+    let mut last_deep_rock = None;
+
+    let mut rock_num = 0;
+    while rock_num < rocks_max {
+        let rock_idx = rock_num % rock_clusters.len();
+        let jet_idx = gas_jet_idx % gas_jets.len();
+
+        if ! skipped {
+            let surface = chamber.surface();
+            let closed = surface[SURFACE_DEPTH as usize] == 0;
+            let state = (rock_idx, jet_idx, surface);
+
+            if let Some(&(height, rocks)) = seen.get(&state).filter(|&&(_, rocks)| closed || last_deep_rock.is_none_or(|deep| deep < rocks)) {
+                let cycle_num_rocks = rock_num - rocks;
+                let cycle_rock_height = chamber.height_reached - height;
+                let repeats = (rocks_max - rock_num) / cycle_num_rocks;
+                let height_diff = repeats as u64 * cycle_rock_height;
+
+                let mut new_rested: HashMap<i64, HashSet<Point>> = HashMap::new();
+                for (&key, points) in chamber.rested.iter() {
+                    let new_points = points
+                        .iter()
+                        .map(|&Point { x, y }| Point::new(x, y + height_diff as i64))
+                        .collect();
+                    new_rested.insert((key as u64 + height_diff) as i64, new_points);
+                }
+
+                chamber.rested = new_rested;
+
+                rock_num += repeats * cycle_num_rocks;
+                chamber.height_reached += height_diff;
+                chamber.bottom += height_diff as i64;
+                skipped = true;
+            } else {
+                seen.insert(state, (chamber.height_reached, rock_num));
+            }
+        }
+
+        if rock_num == rocks_max {
+            break;
+        }
+
+        let raw_rock_cluster = rock_clusters[rock_idx].clone();
+
+        let rock_cluster = raw_rock_cluster
+            .move_by(Point::new(2, chamber.height_reached as i64 + 3));
+
+        let top = chamber.height_reached as i64;
+        let rested = chamber.simulate_rock_fall(
+            gas_jets,
+            &mut gas_jet_idx,
+            &rock_cluster,
+            renderer.as_deref_mut(),
+        );
+
+        // It ran into something below the surface, which could be different the next time around
+        if rested.points.iter().any(|p| p.y - 1 < top - SURFACE_DEPTH) {
+            last_deep_rock = Some(rock_num);
+        }
+
+        rock_num += 1;
+    }
+
+    if let Some(renderer) = renderer {
+        let world = tower_world(&chamber, gas_jets);
+
+        // The top of the tower along with the height reached
+        renderer.finish_scene(&Scene {
+            world,
+            focus: world.min,
+            draw: &|view| Some(tower_frame(&chamber, gas_jets, gas_jet_idx % gas_jets.len(), None, view)),
+        });
+    }
+
+
+    chamber.height_reached
+}
+
+/// `size` random jets
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1)).map(|_| *rng.pick(&['<', '>'])).collect::<String>() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input, None), "3068");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input, None), "1514285714288");
+    }
+
+    #[test]
+    fn tower_snapshot() {
+        let mut snapshot = render::Snapshot::new();
+        part1(&parse(EXAMPLE).unwrap(), Some(&mut snapshot));
+
+        render::assert_snapshot("day17/snapshots/part1.txt", &snapshot);
+    }
+
+    /// Drops every single rock into a tower of rows of cells
+    fn simulate_brute_force(gas_jets: &[Direction], rocks_max: usize) -> u64 {
+        let rocks = RockCluster::get_rock_clusters();
+        let mut tower: Vec<[bool; 7]> = vec![];
+        let mut jets = gas_jets.iter().cycle();
+
+        let fits = |tower: &Vec<[bool; 7]>, rock: &RockCluster| rock.points.iter().all(|p| {
+            (0..7).contains(&p.x) && p.y >= 0 && ! tower.get(p.y as usize).is_some_and(|row| row[p.x as usize])
+        });
+
+        for rock in rocks.iter().cycle().take(rocks_max) {
+            let mut rock = rock.move_by(Point::new(2, tower.len() as i64 + 3));
+
+            loop {
+                let pushed = rock.move_by(jets.next().unwrap().to_move_delta());
+                if fits(&tower, &pushed) {
+                    rock = pushed;
+                }
+
+                let fallen = rock.move_by(Point::new(0, -1));
+                if ! fits(&tower, &fallen) {
+                    break;
+                }
+
+                rock = fallen;
+            }
+
+            for p in &rock.points {
+                while tower.len() <= p.y as usize {
+                    tower.push([false; 7]);
+                }
+
+                tower[p.y as usize][p.x as usize] = true;
+            }
+        }
+
+        tower.len() as u64
+    }
+
+    #[test]
+    fn cycles_only_start_once_the_tower_repeats() {
+        // Found by the property test below, both of them used to skip ahead by cycles that weren't ones
+        let cases = [("<<><<>><>><><>>>>><><<<<>>><><>", 564), ("<>><>>><<><>>><<><<<><><>><>>>><><><>", 313)];
+
+        for (jets, rocks_max) in cases {
+            let gas_jets = parse(jets).unwrap();
+
+            assert_eq!(simulate(&gas_jets, rocks_max, None), simulate_brute_force(&gas_jets, rocks_max), "{}", jets);
+        }
+    }
+
+    #[test]
+    fn cycles_are_found_above_shafts_that_never_fill() {
+        // The rocks never get to the other side, which stays open down to the floor. Part 2 used to never finish
+        for jets in ["<", ">", "<<<<>"] {
+            let gas_jets = parse(jets).unwrap();
+
+            assert_eq!(simulate(&gas_jets, 2500, None), simulate_brute_force(&gas_jets, 2500), "{}", jets);
+        }
+
+        assert_eq!(part2(&parse("<").unwrap(), None), "2200000000000");
+
+        // A rock falls far down a shaft on every cycle here, which only gets closed off between them
+        assert_eq!(part2(&parse(">>><<><>><<<>><>>><<<>>><<<<>><<>>").unwrap(), None), "1548387096772");
+    }
+
+    fn jet() -> impl Strategy<Value = Direction> {
+        any::<bool>().prop_map(|left| if left { Direction::Left } else { Direction::Right })
+    }
+
+    proptest! {
+        // Every case drops up to thousands of rocks twice
+        #![proptest_config(ProptestConfig::with_cases(100))]
+
+        // Short patterns repeat early, so that the cycles get skipped well before the last rock
+        #[test]
+        fn simulate_matches_brute_force(gas_jets in vec(jet(), 1..=40), rocks_max in 0..=2500usize) {
+            prop_assert_eq!(simulate(&gas_jets, rocks_max, None), simulate_brute_force(&gas_jets, rocks_max));
+        }
+    }
+}
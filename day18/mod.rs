@@ -0,0 +1,184 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{geometry::Point3, error::{parse_number_in, ParseError}, generate::Rng, solution::{Params, Solution}};
+
+/// The highest coordinate of a cube, part 2 floods the box around the droplet which grows with the cube of it
+const MAX_COORD: i32 = 50;
+
+fn parse(data: &str) -> Result<Vec<Point3>, ParseError> {
+    if data.trim().is_empty() {
+        return Err(ParseError::end_of_input(data, "expected at least one cube"));
+    }
+
+    data.trim()
+        .lines()
+        .map(|l| {
+            let split: Vec<&str> = l.trim()
+                .split(',')
+                .collect();
+
+            let [x, y, z] = split[..] else {
+                return Err(ParseError::at(data, l.trim(), "expected a cube like 2,2,2"));
+            };
+
+            Ok(Point3 {
+                x: parse_number_in(data, x, 0..=MAX_COORD)?,
+                y: parse_number_in(data, y, 0..=MAX_COORD)?,
+                z: parse_number_in(data, z, 0..=MAX_COORD)?,
+            })
+        }).collect()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Point3>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(input)
+    }
+}
+
+
+fn part1(droplets: &[Point3]) -> String {
+    let map: HashSet<_> = HashSet::from_iter(droplets);
+
+    let mut surface_area = 0u32;
+
+    for droplet in droplets {
+        let faces = droplet
+            .neighbours6()
+            .filter(|x| ! map.contains(x))
+            .count();
+
+        surface_area += faces as u32;
+    }
+
+    surface_area.to_string()
+}
+
+enum CoordType {
+    Min,
+    Max,
+}
+
+fn get_coord<'a, F>(
+    map: &'a HashSet<&'a Point3>,
+    coord_type: CoordType,
+    get_key: F,
+) -> &'a Point3 where F: FnMut(&&&Point3) -> i32 {
+    let iter = map.iter();
+
+    match coord_type {
+        CoordType::Min => iter.min_by_key(get_key).unwrap(),
+        CoordType::Max => iter.max_by_key(get_key).unwrap(),
+    }
+}
+
+fn part2(droplets: &[Point3], ) -> String {
+    let map: HashSet<_> = HashSet::from_iter(droplets);
+
+    let min_x = get_coord(&map, CoordType::Min, |p| p.x).x;
+    let min_y = get_coord(&map, CoordType::Min, |p| p.y).y;
+    let min_z = get_coord(&map, CoordType::Min, |p| p.z).z;
+
+    let max_x = get_coord(&map, CoordType::Max, |p| p.x).x;
+    let max_y = get_coord(&map, CoordType::Max, |p| p.y).y;
+    let max_z = get_coord(&map, CoordType::Max, |p| p.z).z;
+
+    let min = Point3 { x: min_x - 1, y: min_y - 1, z: min_z - 1 };
+    let max = Point3 { x: max_x + 1, y: max_y + 1, z: max_z + 1 };
+
+    let mut flood: HashSet<Point3> = HashSet::new();
+    let mut stack: VecDeque<Point3> = VecDeque::new();
+
+    flood.insert(min);
+    stack.push_back(min);
+
+    while let Some(point) = stack.pop_front() {
+        let neighbors: Vec<Point3> = point
+            .neighbours6()
+            .filter(|p| {
+                ! map.contains(p) &&
+                ! flood.contains(p) &&
+                p.is_within(&min, &max)
+            }).collect();
+
+
+        neighbors.iter().for_each(|p| {
+            stack.push_back(*p);
+            flood.insert(*p);
+        });
+    }
+
+    let mut surface_area = 0u32;
+
+    for point in flood.iter() {
+        let touched_faces = point
+            .neighbours6()
+            .filter(|x| map.contains(x))
+            .count();
+
+        surface_area += touched_faces as u32;
+    }
+
+    surface_area.to_string()
+}
+
+/// `size` distinct cubes (1 to 60000) packed into a box, so that there are pockets of air inside the droplet
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(1, 60_000);
+    let side = ((2 * count) as f64).cbrt().ceil().max(2.0) as i64;
+    let mut cubes = HashSet::new();
+    let mut lines = String::new();
+
+    while cubes.len() < count {
+        let cube = (rng.range(1..=side), rng.range(1..=side), rng.range(1..=side));
+
+        if cubes.insert(cube) {
+            lines += &format!("{},{},{}\n", cube.0, cube.1, cube.2);
+        }
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input), "64");
+    }
+
+    #[test]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input), "58");
+    }
+
+    #[test]
+    fn parse_rejects_cubes_outside_of_the_box() {
+        let err = parse("1,1,1\n2147483647,1,1\n").unwrap_err();
+
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "2147483647"));
+        assert_eq!(err.message, "expected a number from 0 to 50");
+
+        let input = parse("0,0,0\n50,50,50\n").unwrap();
+        assert_eq!((part1(&input), part2(&input)), (String::from("12"), String::from("12")));
+    }
+}
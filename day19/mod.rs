@@ -1,6 +1,9 @@
-use std::{fs, str::FromStr, collections::HashMap};
+use std::{str::FromStr, collections::HashMap};
+
+use crate::{error::{parse_number, parse_number_in, ParseError}, generate::Rng, solution::{Params, Solution}};
 
 #[derive(Debug)]
+#[allow(dead_code)] // Unused until part 2 is done
 enum Material {
     Ore,
     Clay,
@@ -11,27 +14,23 @@ enum Material {
 #[derive(Debug)]
 struct MaterialRequirement(Material, u32);
 
-#[derive(Debug)]
-struct ParseMaterialRequirementError;
-
 impl FromStr for MaterialRequirement {
-    type Err = ParseMaterialRequirementError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = s.trim().split(" ").collect();
-        let ore_amount: u32 = parts
-            .get(0)
-            .ok_or(ParseMaterialRequirementError)
-            .and_then(|x| x.parse::<u32>().map_err(|_| ParseMaterialRequirementError))?;
-        let ore_name = parts
-            .get(1)
-            .ok_or(ParseMaterialRequirementError)?;
-
-        let result = match *ore_name {
+        let (raw_amount, ore_name) = s
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| ParseError::at(s, s.trim(), "expected a cost like `4 ore`"))?;
+
+        // Free robots would multiply without end
+        let ore_amount: u32 = parse_number_in(s, raw_amount, 1..=1000)?;
+
+        let result = match ore_name {
             "ore" => MaterialRequirement(Material::Ore, ore_amount),
             "clay" => MaterialRequirement(Material::Clay, ore_amount),
             "obsidian" => MaterialRequirement(Material::Obsidian, ore_amount),
-            _ => return Err(ParseMaterialRequirementError),
+            _ => return Err(ParseError::at(s, ore_name, "unknown material, expected ore, clay or obsidian")),
         };
 
         Ok(result)
@@ -40,7 +39,8 @@ impl FromStr for MaterialRequirement {
 
 
 #[derive(Debug)]
-struct Blueprint {
+#[allow(dead_code)] // Unused until part 2 is done
+pub struct Blueprint {
     id: u32,
     ore_robot: Vec<MaterialRequirement>,
     clay_robot: Vec<MaterialRequirement>,
@@ -49,61 +49,71 @@ struct Blueprint {
 }
 
 impl Blueprint {
-    fn parse_ore_name(data: &str) -> Option<String> {
-        let ore_name = data.split(" ").nth(1)?;
-        Some(ore_name.to_owned())
+    // Both of these take the whole blueprint as `s` so that errors point at the right place
+    fn parse_ore_name<'a>(s: &str, data: &'a str) -> Result<&'a str, ParseError> {
+        data.split_whitespace()
+            .next()
+            .ok_or_else(|| ParseError::at(s, data.trim(), "expected `<material> robot`"))
     }
 
-    fn parse_ore_requirements(data: &str) -> Option<Vec<MaterialRequirement>> {
+    fn parse_ore_requirements(s: &str, data: &str) -> Result<Vec<MaterialRequirement>, ParseError> {
         let parts = data.split("and");
 
         let mut requirements: Vec<MaterialRequirement> = vec![];
 
         for part in parts {
-            requirements.push(part.trim().trim_end_matches(".").parse().ok()?);
+            let part = part.trim().trim_end_matches('.');
+
+            requirements.push(part.parse().map_err(|err: ParseError| err.within(s, part))?);
         }
         
-        Some(requirements)
+        Ok(requirements)
     }
 }
 
-#[derive(Debug)]
-struct ParseBlueprintError;
-
 impl FromStr for Blueprint {
-    type Err = ParseBlueprintError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let split: Vec<&str> = s.split(":").collect();
-        let part1 = split.get(0).ok_or(ParseBlueprintError)?;
-        let id: u32 = part1.trim_start_matches("Blueprint ")
-            .parse().map_err(|_| ParseBlueprintError)?;
+        let (part1, part2) = s
+            .split_once(':')
+            .ok_or_else(|| ParseError::at(s, s.trim(), "expected `Blueprint <id>: ...`"))?;
+
+        let raw_id = part1
+            .trim()
+            .strip_prefix("Blueprint ")
+            .ok_or_else(|| ParseError::at(s, part1.trim(), "expected `Blueprint <id>`"))?;
+
+        let id: u32 = parse_number(s, raw_id)?;
 
         let mut ore_robot = vec![];
         let mut clay_robot = vec![];
         let mut obsidian_robot = vec![];
         let mut geode_robot = vec![];
 
-        let part2 = split.get(1).ok_or(ParseBlueprintError)?;
-
         for orestr in part2.trim().trim_start_matches("Each").split("Each") {
-            let parts: Vec<&str> = orestr.split("costs").collect();
-            let name_part = parts.get(0).ok_or(ParseBlueprintError)?;
-            let requirements_part = parts.get(1).ok_or(ParseBlueprintError)?;
-            let name = Blueprint::parse_ore_name(&name_part)
-                .ok_or(ParseBlueprintError)?;
-            let requirements = Blueprint::parse_ore_requirements(&requirements_part)
-                .ok_or(ParseBlueprintError)?;
-
-            match name.as_str() {
+            let (name_part, requirements_part) = orestr
+                .split_once("costs")
+                .ok_or_else(|| ParseError::at(s, orestr.trim(), "expected `<material> robot costs ...`"))?;
+
+            let name = Blueprint::parse_ore_name(s, name_part)?;
+            let requirements = Blueprint::parse_ore_requirements(s, requirements_part)?;
+
+            match name {
                 "ore" => { ore_robot = requirements },
                 "clay" => { clay_robot = requirements },
                 "obsidian" => { obsidian_robot = requirements },
                 "geode" => { geode_robot = requirements },
-                _ => return Err(ParseBlueprintError)
+                _ => return Err(ParseError::at(s, name, "unknown robot, expected ore, clay, obsidian or geode")),
             }
         }
 
+        let robots = [("ore", &ore_robot), ("clay", &clay_robot), ("obsidian", &obsidian_robot), ("geode", &geode_robot)];
+
+        if let Some((name, _)) = robots.iter().find(|(_, requirements)| requirements.is_empty()) {
+            return Err(ParseError::at(s, s.trim(), format!("the blueprint doesn't say what the {} robot costs", name)));
+        }
+
         Ok(Blueprint {
             id,
             ore_robot,
@@ -114,23 +124,32 @@ impl FromStr for Blueprint {
     }
 }
 
-fn parse(input: &str) -> Vec<Blueprint> {
+fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError> {
     input.lines()
-        .map(|x| x.parse().expect("Failed to parse blueprint"))
+        .filter(|x| ! x.trim().is_empty())
+        .map(|x| x.parse().map_err(|err: ParseError| err.within(input, x)))
         .collect()
 }
 
-fn main() {
-    let contents = fs::read_to_string("./exampleinput")
-        .expect("File not found");
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<Blueprint>;
 
-    let blueprints = parse(&contents);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
 
-    println!("Part 1: {}", part1(&blueprints));
-    println!("Part 2: {}", part2(&blueprints));
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(input)
+    }
 }
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
 struct State {
     ore_robots: u32,
     ore: u32,
@@ -147,6 +166,12 @@ struct State {
     pending_geode_robots: u32,
 }
 
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Ord for State {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         if self.geodes_open != other.geodes_open {
@@ -222,7 +247,7 @@ impl State {
 
     fn manufacture_robots(
         &mut self,
-        requirements: &Vec<MaterialRequirement>,
+        requirements: &[MaterialRequirement],
     ) -> u32 {
         let mut total: u32 = u32::MAX;
 
@@ -258,7 +283,7 @@ impl State {
     }
 
 
-    fn meets_requirements(&self, requirements: &Vec<MaterialRequirement>) -> bool {
+    fn meets_requirements(&self, requirements: &[MaterialRequirement]) -> bool {
         let mut total: u32 = u32::MAX;
 
         for requirement in requirements {
@@ -280,19 +305,22 @@ impl State {
     }
 }
 
-fn part1(blueprints: &Vec<Blueprint>) -> String {
+fn part1(blueprints: &[Blueprint]) -> String {
     let state = State::new(1);
     let mut memo: HashMap<(State, u32), State> = HashMap::new();
     let mut max_cache: HashMap<u32, State> = HashMap::new();
-    let result = simulate_blueprint(&blueprints[0], &state, 24, &mut memo, &mut max_cache);
-    println!("{result:?}");
+    let Some(blueprint) = blueprints.first() else {
+        return String::from("");
+    };
+
+    simulate_blueprint(blueprint, &state, 24, &mut memo, &mut max_cache);
 
     String::from("")
 }
 
 
 
-fn part2(blueprints: &Vec<Blueprint>) -> String {
+fn part2(_blueprints: &[Blueprint]) -> String {
     
 
     String::from("")
@@ -380,7 +408,7 @@ fn simulate_blueprint(
         states.push(simulate_blueprint(blueprint, &state2, duration - 1, memo, max_cache));
     }
     
-    if states.len() == 0 {
+    if states.is_empty() {
         let mut state1 = state.clone();
         state1.mine_material();
         state1.commit_manufactured_robots();
@@ -422,3 +450,48 @@ fn simulate_blueprint(
         result
     }
 }
+
+/// `size` blueprints with random costs
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (1..=size.max(1))
+        .map(|id| {
+            format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                id,
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(2..=4),
+                rng.range(5..=20),
+                rng.range(2..=4),
+                rng.range(5..=20),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    fn parses_the_example() {
+        let blueprints = parse(EXAMPLE).unwrap();
+
+        assert_eq!(blueprints.iter().map(|b| b.id).collect::<Vec<u32>>(), vec![1, 2]);
+        assert_eq!(blueprints[0].geode_robot.len(), 2);
+    }
+
+    #[test]
+    #[ignore = "part 1 isn't solved yet"]
+    fn example_part1() {
+        assert_eq!(part1(&parse(EXAMPLE).unwrap()), "33");
+    }
+
+    #[test]
+    #[ignore = "part 2 isn't solved yet"]
+    fn example_part2() {
+        assert_eq!(part2(&parse(EXAMPLE).unwrap()), "3472");
+    }
+}
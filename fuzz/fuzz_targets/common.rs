@@ -0,0 +1,61 @@
+//! The checks that the fuzz targets run on every input, `tests/parsers.rs` runs them too with the stable toolchain
+
+use std::panic::{self, AssertUnwindSafe};
+
+use aoc2022::{days::{self, day13::Packet}, error::ParseError, input, parallel, solution::Params};
+
+/// Parses `input` as the input of `day` and solves both of its parts, neither of which must ever panic,
+/// and checks what came out of it
+pub fn check(day: u8, input: &str) {
+    let solution = days::get(day).expect("Every fuzz target belongs to a day");
+
+    match solution.parse(input) {
+        Ok(parsed) => {
+            if let Some(pairs) = parsed.downcast_ref::<Vec<(Packet, Packet)>>() {
+                for packet in pairs.iter().flat_map(|(left, right)| [left, right]) {
+                    check_round_trip(packet);
+                }
+            }
+
+            // The settings of the examples keep day 15 from scanning 4000000 rows
+            let params = Params { example: true, ..Params::default() };
+
+            for part in [1, 2] {
+                let solved = panic::catch_unwind(AssertUnwindSafe(|| match part {
+                    1 => solution.part1(parsed.as_ref(), &params),
+                    _ => solution.part2(parsed.as_ref(), &params),
+                }));
+
+                if let Err(payload) = solved {
+                    panic!("part {} of day {} panicked on an input that parsed: {}", part, day, parallel::panic_message(payload.as_ref()));
+                }
+            }
+        },
+        Err(err) => check_error(&err, &input::normalize(input)),
+    }
+}
+
+/// Printing a packet gives back the text it was parsed from, without the whitespace
+fn check_round_trip(packet: &Packet) {
+    let printed = packet.to_string();
+
+    assert_eq!(printed.parse::<Packet>().as_ref(), Ok(packet), "{}", printed);
+}
+
+/// The error points at the text it quotes in the normalized input, and can be shown
+fn check_error(err: &ParseError, input: &str) {
+    assert!(err.line >= 1 && err.column >= 1, "{:?}", err);
+
+    let line_start: usize = input.split_inclusive('\n').take(err.line - 1).map(str::len).sum();
+    let line = input[line_start..].split('\n').next().unwrap_or("");
+    let column = line.char_indices().map(|(i, _)| i).chain([line.len()]).nth(err.column - 1);
+
+    let Some(column) = column else {
+        panic!("{}:{} is past the end of {:?}", err.line, err.column, line);
+    };
+
+    let offset = line_start + column;
+    assert!(input[offset..].starts_with(&err.text), "{:?} isn't at {}:{} of {:?}", err.text, err.line, err.column, input);
+
+    err.diagnostic("input", input);
+}
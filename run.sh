@@ -1,15 +1,11 @@
 # !/bin/bash
 
-day=$1
-
-if [ ! -d "$day" ]
+if [ -z "$1" ]
 then
-    echo "Usage: run.sh <dayxx>"
-    echo "Please enter a valid day input"
-    echo "Use the folder name of the solutions"
+    echo "Usage: run.sh <day|all>"
+    echo "Please enter a valid day number, eg: ./run.sh 14"
+    echo "Use \"all\" to run the solutions of all of the days"
     exit 1
 fi
 
-cd $day
-
-cargo run --bin "$@"
+cargo run --release --bin aoc -- run "$@"
@@ -0,0 +1,62 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::days;
+
+/// Where the recorded answers are kept by default, relative to the root of the repository
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+/// The known answers of each day, keyed by the day's directory and the name of the input file:
+///
+/// ```toml
+/// [day01.exampleinput]
+/// part1 = "24000"
+/// part2 = "45000"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, PartAnswers>>);
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PartAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+
+        Answers::parse(&contents)
+            .map_err(|err| format!("Failed to parse {}: {}", path.display(), err))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        toml::from_str(contents).map_err(|err| err.to_string())
+    }
+
+    /// The recorded answer of a part (1 or 2) of a day for the given input file
+    pub fn get(&self, day: u8, file_name: &str, part: u8) -> Option<&str> {
+        let answers = self.0.get(&days::dir_name(day))?.get(file_name)?;
+
+        match part {
+            1 => answers.part1.as_deref(),
+            2 => answers.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// A part counts as solved once the answer of its example is recorded
+    pub fn is_solved(&self, day: u8, part: u8) -> bool {
+        self.get(day, "exampleinput", part).is_some()
+    }
+}
+
+/// Whether an answer matches the recorded one, surrounding whitespace is ignored
+/// since multi-line answers are hard to write down exactly in a toml file
+pub fn matches(expected: &str, actual: &str) -> bool {
+    expected.trim() == actual.trim()
+}
@@ -0,0 +1,113 @@
+use std::time::{Duration, Instant};
+
+/// Calls `f` `runs` times (at least once) and returns the result of the last call
+/// along with how long each of the calls took
+pub fn measure<T>(runs: u32, mut f: impl FnMut() -> T) -> (T, Vec<Duration>) {
+    let mut samples = Vec::with_capacity(runs as usize);
+
+    loop {
+        let start = Instant::now();
+        let result = f();
+        samples.push(start.elapsed());
+
+        if samples.len() >= runs as usize {
+            return (result, samples);
+        }
+    }
+}
+
+/// Summary of repeated measurements of the same thing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Population standard deviation
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Returns `None` when there are no samples
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let min = *sorted.first()?;
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2
+        } else {
+            sorted[mid]
+        };
+
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>() / n;
+
+        Some(Stats {
+            min,
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(list: &[u64]) -> Vec<Duration> {
+        list.iter().map(|&n| Duration::from_millis(n)).collect()
+    }
+
+    /// The mean and the deviation go through f64s, so they can be off by a few nanoseconds
+    fn assert_close(actual: Duration, expected: Duration) {
+        assert!(actual.abs_diff(expected) < Duration::from_micros(1), "{:?} isn't {:?}", actual, expected);
+    }
+
+    #[test]
+    fn median_of_an_odd_number_of_samples_is_the_middle_one() {
+        let stats = Stats::from_samples(&ms(&[3, 1, 2])).unwrap();
+
+        assert_eq!((stats.min, stats.median), (Duration::from_millis(1), Duration::from_millis(2)));
+    }
+
+    #[test]
+    fn median_of_an_even_number_of_samples_is_between_the_middle_ones() {
+        let stats = Stats::from_samples(&ms(&[5, 2, 9, 4, 7, 4, 5, 4])).unwrap();
+
+        assert_eq!((stats.min, stats.median), (Duration::from_millis(2), Duration::from_micros(4500)));
+    }
+
+    #[test]
+    fn mean_and_standard_deviation_of_the_population() {
+        let stats = Stats::from_samples(&ms(&[5, 2, 9, 4, 7, 4, 5, 4])).unwrap();
+
+        assert_close(stats.mean, Duration::from_millis(5));
+        assert_close(stats.stddev, Duration::from_millis(2));
+    }
+
+    #[test]
+    fn a_single_sample_doesnt_deviate() {
+        let stats = Stats::from_samples(&ms(&[7])).unwrap();
+
+        assert_eq!((stats.min, stats.median), (Duration::from_millis(7), Duration::from_millis(7)));
+        assert_close(stats.mean, Duration::from_millis(7));
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn measure_runs_at_least_once() {
+        let mut calls = 0;
+        let (result, samples) = measure(0, || { calls += 1; calls });
+        assert_eq!((result, samples.len()), (1, 1));
+
+        let (result, samples) = measure(5, || { calls += 1; calls });
+        assert_eq!((result, samples.len()), (6, 5));
+    }
+}
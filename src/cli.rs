@@ -0,0 +1,686 @@
+use std::{path::{Path, PathBuf}, str::FromStr, sync::{Arc, Mutex}, time::{Duration, Instant, SystemTime, UNIX_EPOCH}};
+
+use crate::{answers::{self, Answers}, bench::{self, Stats}, config::Config, days, error::ParseError, fetch::{self, Origin}, generate::{self, Rng}, input::InputSource, output::{self, Answer, Durations, Format, Record, Timings}, parallel, render::{ImageOptions, Palette, RecordFormat, Recording}, scaffold, serve::Server, solution::{DynSolution, Params}, submit::{self, Verdict}, verify};
+
+const USAGE: &str = "\
+Usage: aoc run <day|all> [options]
+       aoc verify [day|all] [--example] [--answers <path>]
+       aoc fetch <day> [--force]
+       aoc submit <day> <part> [--answer <value>]
+       aoc new <day>
+       aoc generate <day> [--size <n>] [--seed <n>]
+       aoc serve [--port <n>] [--answers <path>]
+
+Options:
+    --input <path>    Reads the input from the given file instead of dayXX/input
+    -                 Reads the input from stdin
+    --example         Reads the example input from dayXX/exampleinput
+    --row <n>         Day 15: The row to check in part 1 (default: 2000000, 10 with --example)
+    --bound <n>       Day 15: The size of the area to search in part 2 (default: 4000000, 20 with --example)
+    --time            Prints how long parsing and each of the parts took
+    --bench <n>       Runs every phase n times and prints the min, median, mean and stddev of each
+    --format <fmt>    Prints the results as text (default) or as json, one object per line and part
+    --visualize       Draws days 9, 10, 12, 14 and 17 in the terminal while solving them (needs the visualize feature)
+    --delay-ms <n>    How long to wait between the frames of --visualize, each day has its own default.
+                      While visualizing, space pauses, s steps, +/- change the speed, the arrows pan,
+                      f follows the action again, e jumps to the end and q quits
+    --record <path>   Records the visualization of a day, to an asciicast file (.cast) which asciinema can replay,
+                      an animated GIF (.gif), a PNG per frame (.png, numbered after the name of the file)
+                      or the plain text of every frame (.txt)
+    --cell-size <n>   How many pixels wide and high each cell of a .gif or .png recording is (default: 4)
+    --palette <path>  Reads the colours of a .gif or .png recording from the given toml file
+    --jobs <n>        Runs all of the parts on n threads at once and prints a table of their answers and times,
+                      a part that panics or fails doesn't stop the others
+
+Verify options:
+    --example         Only checks the answers of dayXX/exampleinput, instead of both inputs
+    --answers <path>  Reads the recorded answers from the given file instead of answers.toml
+
+Fetch options:
+    --force           Overwrites the dayXX/input that's already there. The session token is read from AOC_SESSION
+                      or ~/.config/aoc/config.toml, the downloaded inputs are kept in ~/.cache/aoc
+
+Submit options:
+    --answer <value>  Submits the given answer instead of the one of the solution, eg: the letters of day 10.
+                      Every submission is written down, answers that are known to be wrong aren't submitted again
+
+Generate options:
+    --size <n>        How big the input is, what it counts depends on the day and `aoc generate` lists it
+                      (default: about the size of the puzzle input)
+    --seed <n>        The seed of the random input, the same seed and size always give the same input
+                      (default: the current time, printed to stderr)
+
+Serve options:
+    --port <n>        The port to listen on at 127.0.0.1 (default: 2022). POST /day/<n>/part/<p> solves a part with
+                      the body as its input and GET /days lists the days, both answer with json
+    --answers <path>  Reads which parts are solved from the given file instead of answers.toml
+
+Examples:
+    aoc run 14                 Runs the solution of the 14th day
+    aoc run all --example      Runs the solutions of all of the days against their examples
+    cat input | aoc run 3 -    Runs the solution of the 3rd day against the piped input
+    aoc run 16 --bench 10      Benchmarks the 16th day over 10 runs
+    aoc run all --format json  Prints the answers and timings of all of the days as json
+    aoc run all --jobs 8       Runs all of the days on 8 threads
+    aoc run 10 --visualize     Draws the screen of the 10th day while it's being solved
+    aoc run 14 --example --record sand.cast
+                               Records the falling sand of the 14th day
+    aoc run 12 --record hills.gif --cell-size 8
+                               Records the path up the hill of the 12th day as an animated GIF
+    aoc verify --example       Compares the answers of all of the examples with answers.toml
+    aoc fetch 20               Downloads the input of the 20th day to day20/input
+    aoc submit 14 2            Solves the second part of the 14th day and submits the answer
+    aoc new 20                 Creates day20/ with a skeleton solution and registers it in src/days.rs
+    aoc generate 15 --size 100 --seed 7 | aoc run 15 - --row 50 --bound 100
+                               Solves a random input of the 15th day with a 100 by 100 area to search
+    aoc serve --port 8080      Solves the inputs that are posted to http://127.0.0.1:8080/day/<n>/part/<p>";
+
+#[derive(Debug, PartialEq, Eq)]
+enum Target {
+    Day(u8),
+    All,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Timing {
+    Off,
+    /// Time a single run
+    Once,
+    /// Repeat every phase this many times
+    Bench(u32),
+}
+
+#[derive(Debug)]
+struct RunArgs {
+    target: Target,
+    source: InputSource,
+    params: Params,
+    timing: Timing,
+    format: Format,
+    record: Option<PathBuf>,
+    image: ImageOptions,
+    /// How many threads run the parts of `all` at once
+    jobs: Option<usize>,
+}
+
+#[derive(Debug)]
+struct VerifyArgs {
+    target: Target,
+    sources: Vec<InputSource>,
+    answers_path: PathBuf,
+}
+
+#[derive(Debug)]
+struct FetchArgs {
+    day: u8,
+    force: bool,
+}
+
+#[derive(Debug)]
+struct SubmitArgs {
+    day: u8,
+    part: u8,
+    /// Given on the command line, instead of running the solution
+    answer: Option<String>,
+}
+
+#[derive(Debug)]
+struct ServeArgs {
+    port: u16,
+    answers_path: PathBuf,
+}
+
+#[derive(Debug)]
+struct GenerateArgs {
+    day: u8,
+    size: Option<usize>,
+    seed: Option<u64>,
+}
+
+/// Entry point of the `aoc` binary, returns the process exit code
+pub fn main(args: &[String]) -> i32 {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let mut quit = false;
+
+    let result = match args.split_first() {
+        Some((&"run", rest)) => parse_run_args(rest).and_then(|args| {
+            let result = run(&args);
+            quit = args.params.quit();
+            result
+        }),
+        Some((&"verify", rest)) => parse_verify_args(rest).and_then(|args| verify(&args)),
+        Some((&"fetch", rest)) => parse_fetch_args(rest).and_then(|args| fetch(&args)),
+        Some((&"submit", rest)) => parse_submit_args(rest).and_then(|args| submit(&args)),
+        Some((&"new", [day])) => parse_puzzle_day(day).and_then(new_day),
+        Some((&"generate", rest)) => parse_generate_args(rest).and_then(|args| generate(&args)),
+        Some((&"serve", rest)) => parse_serve_args(rest).and_then(|args| serve(&args)),
+        _ => Err(String::from(USAGE)),
+    };
+
+    match result {
+        // Quitting a visualization exits like ctrl-c, once what was recorded so far is saved
+        Ok(()) if quit => 130,
+        Ok(()) => 0,
+        Err(err) => {
+            eprintln!("{}", err);
+            1
+        },
+    }
+}
+
+fn parse_run_args(args: &[&str]) -> Result<RunArgs, String> {
+    let mut target = None;
+    let mut source = InputSource::Puzzle;
+    let mut params = Params::default();
+    let mut timing = Timing::Off;
+    let mut format = Format::Text;
+    let mut record = None;
+    let mut image = ImageOptions::default();
+    let mut jobs = None;
+
+    let mut iter = args.iter();
+
+    while let Some(&arg) = iter.next() {
+        let mut value = || iter.next().copied().ok_or_else(|| format!("Missing value for {}", arg));
+
+        match arg {
+            "--input" => source = match value()? {
+                "-" => InputSource::Stdin,
+                path => InputSource::File(PathBuf::from(path)),
+            },
+            "-" => source = InputSource::Stdin,
+            "--example" => source = InputSource::Example,
+            "--row" => params.row = Some(parse_number(arg, value()?)?),
+            "--bound" => params.bound = Some(parse_number(arg, value()?)?),
+            "--time" => timing = Timing::Once,
+            "--bench" => timing = match parse_number(arg, value()?)? {
+                0 => return Err(String::from("--bench needs at least 1 run")),
+                runs => Timing::Bench(runs),
+            },
+            "--visualize" if cfg!(feature = "visualize") => params.visualize = true,
+            "--visualize" => return Err(String::from("--visualize needs aoc to be built with the visualize feature, eg: cargo run --release --features visualize -- run 10 --visualize")),
+            "--record" => record = Some(PathBuf::from(value()?)),
+            "--cell-size" => image.cell_size = match parse_number(arg, value()?)? {
+                0 => return Err(String::from("--cell-size needs at least 1 pixel")),
+                size => size,
+            },
+            "--palette" => image.palette = Palette::load(Path::new(value()?))?,
+            "--jobs" => jobs = match parse_number(arg, value()?)? {
+                0 => return Err(String::from("--jobs needs at least 1 thread")),
+                n => Some(n),
+            },
+            "--delay-ms" => params.delay = Some(Duration::from_millis(parse_number(arg, value()?)?)),
+            "--format" => format = match value()? {
+                "text" => Format::Text,
+                "json" => Format::Json,
+                other => return Err(format!("Invalid value for {}: {} (expected text or json)", arg, other)),
+            },
+            "all" if target.is_none() => target = Some(Target::All),
+            day if target.is_none() && ! day.starts_with('-') => target = Some(Target::Day(parse_day(day)?)),
+            _ => return Err(format!("Unexpected argument {}\n\n{}", arg, USAGE)),
+        }
+    }
+
+    let target = target.ok_or_else(|| String::from(USAGE))?;
+
+    if target == Target::All && matches!(source, InputSource::File(_) | InputSource::Stdin) {
+        return Err(String::from("A custom input can only be used when running a single day"));
+    }
+
+    if record.is_some() && target == Target::All {
+        return Err(String::from("--record can only be used when running a single day"));
+    }
+
+    // Every run would end up in the recording
+    if record.is_some() && matches!(timing, Timing::Bench(_)) {
+        return Err(String::from("--record can't be used together with --bench"));
+    }
+
+    if let Some(path) = &record {
+        RecordFormat::of(path)?;
+    }
+
+    if jobs.is_some() {
+        if target != Target::All {
+            return Err(String::from("--jobs can only be used when running all of the days"));
+        }
+
+        // The table has the times of the parts already, and the parts draw over each other
+        if timing != Timing::Off || format != Format::Text || params.visualize {
+            return Err(String::from("--jobs can't be used together with --time, --bench, --format json or --visualize"));
+        }
+    }
+
+    params.example = source.is_example();
+
+    Ok(RunArgs { target, source, params, timing, format, record, image, jobs })
+}
+
+fn parse_verify_args(args: &[&str]) -> Result<VerifyArgs, String> {
+    let mut target = Target::All;
+    let mut sources = vec![InputSource::Puzzle, InputSource::Example];
+    let mut answers_path = PathBuf::from(answers::DEFAULT_PATH);
+
+    let mut iter = args.iter();
+
+    while let Some(&arg) = iter.next() {
+        match arg {
+            "--example" => sources = vec![InputSource::Example],
+            "--answers" => {
+                let path = iter.next().ok_or_else(|| format!("Missing value for {}", arg))?;
+                answers_path = PathBuf::from(path);
+            },
+            "all" => target = Target::All,
+            day if ! day.starts_with('-') => target = Target::Day(parse_day(day)?),
+            _ => return Err(format!("Unexpected argument {}\n\n{}", arg, USAGE)),
+        }
+    }
+
+    Ok(VerifyArgs { target, sources, answers_path })
+}
+
+fn parse_fetch_args(args: &[&str]) -> Result<FetchArgs, String> {
+    let mut day = None;
+    let mut force = false;
+
+    for &arg in args {
+        match arg {
+            "--force" => force = true,
+            raw if day.is_none() && ! raw.starts_with('-') => day = Some(parse_puzzle_day(raw)?),
+            _ => return Err(format!("Unexpected argument {}\n\n{}", arg, USAGE)),
+        }
+    }
+
+    let day = day.ok_or_else(|| format!("Missing the day to fetch\n\n{}", USAGE))?;
+
+    Ok(FetchArgs { day, force })
+}
+
+fn parse_submit_args(args: &[&str]) -> Result<SubmitArgs, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut answer = None;
+
+    let mut iter = args.iter();
+
+    while let Some(&arg) = iter.next() {
+        match arg {
+            "--answer" => answer = Some(String::from(*iter.next().ok_or_else(|| format!("Missing value for {}", arg))?)),
+            raw if day.is_none() && ! raw.starts_with('-') => day = Some(parse_puzzle_day(raw)?),
+            raw if part.is_none() && ! raw.starts_with('-') => part = match raw {
+                "1" => Some(1),
+                "2" => Some(2),
+                _ => return Err(format!("Invalid part {}, it's either 1 or 2", raw)),
+            },
+            _ => return Err(format!("Unexpected argument {}\n\n{}", arg, USAGE)),
+        }
+    }
+
+    let (Some(day), Some(part)) = (day, part) else {
+        return Err(format!("Missing the day or the part to submit\n\n{}", USAGE));
+    };
+
+    if answer.is_none() && days::get(day).is_none() {
+        return Err(format!("Day {} is not implemented, --answer submits an answer without running it", day));
+    }
+
+    Ok(SubmitArgs { day, part, answer })
+}
+
+fn parse_generate_args(args: &[&str]) -> Result<GenerateArgs, String> {
+    let mut day = None;
+    let mut size = None;
+    let mut seed = None;
+
+    let mut iter = args.iter();
+
+    while let Some(&arg) = iter.next() {
+        let mut value = || iter.next().copied().ok_or_else(|| format!("Missing value for {}", arg));
+
+        match arg {
+            "--size" => size = Some(parse_number(arg, value()?)?),
+            "--seed" => seed = Some(parse_number(arg, value()?)?),
+            raw if day.is_none() && ! raw.starts_with('-') => day = Some(parse_day(raw)?),
+            _ => return Err(format!("Unexpected argument {}\n\n{}\n\n{}", arg, USAGE, generator_sizes())),
+        }
+    }
+
+    let day = day.ok_or_else(|| format!("Missing the day to generate an input for\n\n{}\n\n{}", USAGE, generator_sizes()))?;
+
+    Ok(GenerateArgs { day, size, seed })
+}
+
+fn parse_serve_args(args: &[&str]) -> Result<ServeArgs, String> {
+    let mut port = 2022;
+    let mut answers_path = PathBuf::from(answers::DEFAULT_PATH);
+
+    let mut iter = args.iter();
+
+    while let Some(&arg) = iter.next() {
+        let mut value = || iter.next().copied().ok_or_else(|| format!("Missing value for {}", arg));
+
+        match arg {
+            "--port" => port = parse_number(arg, value()?)?,
+            "--answers" => answers_path = PathBuf::from(value()?),
+            _ => return Err(format!("Unexpected argument {}\n\n{}", arg, USAGE)),
+        }
+    }
+
+    Ok(ServeArgs { port, answers_path })
+}
+
+/// Any day of the event, implemented or not
+fn parse_puzzle_day(raw: &str) -> Result<u8, String> {
+    raw.parse::<u8>()
+        .ok()
+        .filter(|d| (1..=25).contains(d))
+        .ok_or_else(|| format!("Invalid day {}, the days go from 1 to 25", raw))
+}
+
+fn parse_day(raw: &str) -> Result<u8, String> {
+    raw.parse::<u8>()
+        .ok()
+        .filter(|d| days::get(*d).is_some())
+        .ok_or_else(|| format!("Day {} is not implemented", raw))
+}
+
+fn parse_number<T: FromStr>(flag: &str, raw: &str) -> Result<T, String> {
+    raw.parse()
+        .map_err(|_| format!("Invalid value for {}: {}", flag, raw))
+}
+
+fn run(args: &RunArgs) -> Result<(), String> {
+    match args.target {
+        Target::Day(day) => run_day(day, args),
+        Target::All => match args.jobs {
+            Some(jobs) => run_parallel(jobs, args),
+            None => run_all(args),
+        },
+    }
+}
+
+fn run_parallel(jobs: usize, args: &RunArgs) -> Result<(), String> {
+    let days: Vec<u8> = days::DAYS.iter().map(|&(day, _)| day).collect();
+
+    let start = Instant::now();
+    let runs = parallel::run(&days, jobs, &args.source, &args.params);
+
+    parallel::report(&runs, start.elapsed(), jobs)
+}
+
+fn run_all(args: &RunArgs) -> Result<(), String> {
+    let mut failed = 0;
+
+    for &(day, _) in days::DAYS {
+        if let Err(err) = run_day(day, args) {
+            eprintln!("{}", err);
+            failed += 1;
+        }
+
+        if args.params.quit() {
+            break;
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{} day(s) failed to run", failed));
+    }
+
+    Ok(())
+}
+
+fn run_day(day: u8, args: &RunArgs) -> Result<(), String> {
+    let solution = days::get(day).ok_or_else(|| format!("Day {} is not implemented", day))?;
+    let contents = args.source.read(day)?;
+
+    if args.format == Format::Text {
+        println!("Day {}", day);
+    }
+
+    let recording = args.record.as_ref().map(|_| Arc::new(Mutex::new(Recording::new())));
+    let params = Params { recording: recording.clone(), ..args.params.clone() };
+
+    solve(solution, day, &contents, &params, args)
+        .map_err(|err| err.diagnostic(&args.source.describe(day), &contents))?;
+
+    if let (Some(path), Some(recording)) = (&args.record, recording) {
+        let recording = recording.lock().unwrap_or_else(|err| err.into_inner());
+
+        if recording.is_empty() {
+            return Err(format!("Day {} doesn't have a visualization to record", day));
+        }
+
+        recording.save(path, &format!("Advent of Code 2022, day {}", day), &args.image)?;
+
+        if recording.dropped() > 0 {
+            eprintln!(
+                "The recording was cut short at {} frames, {} frames before the end of the parts were left out",
+                recording.steps().len(), recording.dropped(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn solve(solution: &dyn DynSolution, day: u8, contents: &str, params: &Params, args: &RunArgs) -> Result<(), ParseError> {
+    let runs = match args.timing {
+        Timing::Bench(runs) => runs,
+        _ => 1,
+    };
+
+    let text = args.format == Format::Text;
+
+    let (parsed, parse_times) = bench::measure(runs, || solution.parse(contents));
+    let parsed = parsed?;
+
+    let (part1, part1_times) = bench::measure(runs, || solution.part1(parsed.as_ref(), params));
+    if text {
+        println!("Part 1: {}", part1);
+    }
+
+    // The visualization was quit, the second part isn't shown
+    if params.quit() {
+        return Ok(());
+    }
+
+    let (part2, part2_times) = bench::measure(runs, || solution.part2(parsed.as_ref(), params));
+    if text {
+        println!("Part 2: {}", part2);
+    }
+
+    if args.format == Format::Json {
+        print_records(day, contents, &args.source, &parse_times, [(&part1, &part1_times), (&part2, &part2_times)]);
+        return Ok(());
+    }
+
+    let phases = [("Parse", parse_times), ("Part 1", part1_times), ("Part 2", part2_times)];
+
+    match args.timing {
+        Timing::Off => {},
+        Timing::Once => print_times(&phases),
+        Timing::Bench(runs) => print_stats(runs, &phases),
+    }
+
+    Ok(())
+}
+
+fn print_records(day: u8, contents: &str, source: &InputSource, parse_times: &[Duration], parts: [(&String, &Vec<Duration>); 2]) {
+    let input = source.describe(day);
+    let input_sha256 = output::input_hash(contents);
+    let parse = Timings::from_samples(parse_times).expect("Every phase runs at least once");
+
+    for (part, (answer, times)) in (1..=2).zip(parts) {
+        let record = Record {
+            day,
+            part,
+            answer: Answer::new(answer),
+            input: input.clone(),
+            input_sha256: input_sha256.clone(),
+            durations: Durations {
+                parse,
+                part: Timings::from_samples(times).expect("Every phase runs at least once"),
+            },
+        };
+
+        println!("{}", record.to_json());
+    }
+}
+
+fn print_times(phases: &[(&str, Vec<Duration>)]) {
+    println!("Timings:");
+
+    for (name, times) in phases {
+        let total: Duration = times.iter().sum();
+        println!("    {:<8}{:>12}", name, format!("{:.2?}", total));
+    }
+}
+
+fn print_stats(runs: u32, phases: &[(&str, Vec<Duration>)]) {
+    println!("Timings over {} run(s):", runs);
+    println!("    {:<8}{:>12}{:>12}{:>12}{:>12}", "", "min", "median", "mean", "stddev");
+
+    for (name, times) in phases {
+        let Some(stats) = Stats::from_samples(times) else {
+            continue;
+        };
+
+        println!(
+            "    {:<8}{:>12}{:>12}{:>12}{:>12}",
+            name,
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.mean),
+            format!("{:.2?}", stats.stddev),
+        );
+    }
+}
+
+fn fetch(args: &FetchArgs) -> Result<(), String> {
+    let config = Config::load()?;
+    let dest = PathBuf::from(days::dir_name(args.day)).join("input");
+
+    match fetch::fetch(&config, args.day, &dest, args.force)? {
+        Origin::Site => println!("Downloaded the input of day {} to {}", args.day, dest.display()),
+        Origin::Cache => println!("Copied the input of day {} to {} from {}", args.day, dest.display(), fetch::cache_path(&config, args.day).display()),
+    }
+
+    Ok(())
+}
+
+fn submit(args: &SubmitArgs) -> Result<(), String> {
+    let config = Config::load()?;
+
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => solve_part(args.day, args.part)?,
+    };
+    let answer = answer.trim();
+
+    if answer.is_empty() || answer.contains('\n') {
+        return Err(format!("The answer of day {} part {} has to be read from its picture and given with --answer:\n{}", args.day, args.part, answer));
+    }
+
+    println!("Submitting {} for day {} part {}", answer, args.day, args.part);
+
+    let outcome = submit::submit(&config, args.day, args.part, answer)?;
+
+    if ! outcome.message.is_empty() {
+        println!("{}", outcome.message);
+    }
+
+    match outcome.verdict {
+        Verdict::Correct => Ok(()),
+        verdict => Err(format!("The answer was {}", verdict)),
+    }
+}
+
+/// The answer of a part of a day for its puzzle input
+fn solve_part(day: u8, part: u8) -> Result<String, String> {
+    let solution = days::get(day).ok_or_else(|| format!("Day {} is not implemented", day))?;
+    let source = InputSource::Puzzle;
+    let contents = source.read(day)?;
+
+    let parsed = solution
+        .parse(&contents)
+        .map_err(|err| err.diagnostic(&source.describe(day), &contents))?;
+
+    Ok(match part {
+        1 => solution.part1(parsed.as_ref(), &Params::default()),
+        _ => solution.part2(parsed.as_ref(), &Params::default()),
+    })
+}
+
+fn new_day(day: u8) -> Result<(), String> {
+    let changed = scaffold::new_day(Path::new(""), day)?;
+
+    if changed.is_empty() {
+        println!("Day {} is already set up", day);
+        return Ok(());
+    }
+
+    for path in changed {
+        println!("Wrote {}", path.display());
+    }
+
+    println!("`aoc fetch {}` downloads its input", day);
+    Ok(())
+}
+
+fn generate(args: &GenerateArgs) -> Result<(), String> {
+    let generator = generate::get(args.day)
+        .ok_or_else(|| format!("Day {} doesn't have a generator\n\n{}", args.day, generator_sizes()))?;
+
+    let seed = args.seed.unwrap_or_else(|| {
+        let seed = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_nanos() as u64);
+        eprintln!("Seed: {}", seed);
+        seed
+    });
+    let size = args.size.unwrap_or_else(|| {
+        eprintln!("Size: {} {}", generator.default_size, generator.size);
+        generator.default_size
+    });
+
+    print!("{}", (generator.generate)(&mut Rng::new(seed), size));
+    Ok(())
+}
+
+/// What `--size` counts for every day that has a generator, eg: `  1  elves (default: 250)`
+fn generator_sizes() -> String {
+    let sizes: Vec<String> = generate::GENERATORS
+        .iter()
+        .map(|generator| format!("    {:>2}  {} (default: {})", generator.day, generator.size, generator.default_size))
+        .collect();
+
+    format!("The sizes of the generated inputs:\n{}", sizes.join("\n"))
+}
+
+fn serve(args: &ServeArgs) -> Result<(), String> {
+    // GET /days lists the parts that are solved, the same ones that aoc verify checks
+    let answers = Answers::load(&args.answers_path)?;
+
+    let server = Server::bind(&format!("127.0.0.1:{}", args.port), answers)
+        .map_err(|err| format!("Failed to listen on port {}: {}", args.port, err))?;
+
+    eprintln!("Listening on http://127.0.0.1:{}", args.port);
+    server.run();
+
+    Ok(())
+}
+
+fn verify(args: &VerifyArgs) -> Result<(), String> {
+    let answers = Answers::load(&args.answers_path)?;
+
+    let days: Vec<u8> = match args.target {
+        Target::Day(day) => vec![day],
+        Target::All => days::DAYS.iter().map(|&(day, _)| day).collect(),
+    };
+
+    let checks: Vec<verify::Check> = days
+        .iter()
+        .flat_map(|&day| args.sources.iter().map(move |source| (day, source)))
+        .flat_map(|(day, source)| verify::verify_day(&answers, day, source))
+        .collect();
+
+    verify::report(&checks)
+}
@@ -0,0 +1,154 @@
+use std::{env, fs, path::{Path, PathBuf}, time::Duration};
+
+use serde::Deserialize;
+
+/// The year of the puzzles that this repository solves
+pub const YEAR: u16 = 2022;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent along with every request, so that the site knows who to contact about a misbehaving tool
+pub const USER_AGENT: &str = "aoc2022 (https://github.com/WinterCore/aoc2022)";
+
+/// How to talk to the Advent of Code site, read from a toml file and overridden by the environment:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."     # or AOC_SESSION
+/// base_url = "http://localhost:8080"  # or AOC_BASE_URL
+/// cache_dir = "/tmp/aoc"              # or AOC_CACHE_DIR
+/// history = "/tmp/aoc/answers.jsonl"  # or AOC_HISTORY
+/// ```
+///
+/// The file is `$AOC_CONFIG`, or `~/.config/aoc/config.toml` when that isn't set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The value of the `session` cookie of a logged in browser
+    pub session: Option<String>,
+    pub base_url: String,
+    /// Where the downloaded inputs are kept, so that they're only ever downloaded once
+    pub cache_dir: PathBuf,
+    /// Where every submitted answer is written down, by default `submissions.jsonl` next to the cached inputs
+    pub history: PathBuf,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    session: Option<String>,
+    base_url: Option<String>,
+    cache_dir: Option<PathBuf>,
+    history: Option<PathBuf>,
+}
+
+impl Config {
+    pub fn load() -> Result<Self, String> {
+        let file = match env::var_os("AOC_CONFIG") {
+            Some(path) => ConfigFile::load(Path::new(&path))?,
+            // The default file is optional, everything can come from the environment
+            None => match home_dir("XDG_CONFIG_HOME", ".config") {
+                Some(dir) if dir.join("aoc/config.toml").exists() => ConfigFile::load(&dir.join("aoc/config.toml"))?,
+                _ => ConfigFile::default(),
+            },
+        };
+
+        Ok(Config::new(file, |name| env::var(name).ok()))
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        Ok(Config::new(ConfigFile::parse(contents)?, |_| None))
+    }
+
+    fn new(file: ConfigFile, var: impl Fn(&str) -> Option<String>) -> Self {
+        let cache_dir = var("AOC_CACHE_DIR").map(PathBuf::from)
+            .or(file.cache_dir)
+            .or_else(|| home_dir("XDG_CACHE_HOME", ".cache").map(|dir| dir.join("aoc")))
+            .unwrap_or_else(|| PathBuf::from(".aoc-cache"));
+
+        let history = var("AOC_HISTORY").map(PathBuf::from)
+            .or(file.history)
+            .unwrap_or_else(|| cache_dir.join(YEAR.to_string()).join("submissions.jsonl"));
+
+        Config {
+            session: var("AOC_SESSION").or(file.session)
+                .map(|session| String::from(session.trim()))
+                .filter(|session| ! session.is_empty()),
+            base_url: var("AOC_BASE_URL").or(file.base_url)
+                .map(|url| String::from(url.trim_end_matches('/')))
+                .unwrap_or_else(|| String::from(DEFAULT_BASE_URL)),
+            cache_dir,
+            history,
+        }
+    }
+
+    /// The session token, or an error explaining how to provide one
+    pub fn session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or_else(|| String::from(
+            "No session token, set AOC_SESSION or the session of ~/.config/aoc/config.toml \
+             to the session cookie of a browser that's logged in to adventofcode.com",
+        ))
+    }
+
+    /// The url of a day's puzzle, the other pages of the day are under it
+    pub fn day_url(&self, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, YEAR, day)
+    }
+
+    pub fn cookie(&self) -> Result<String, String> {
+        Ok(format!("session={}", self.session()?))
+    }
+}
+
+/// The http client of the requests to the site
+pub fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(30))
+        .user_agent(USER_AGENT)
+        .build()
+}
+
+impl ConfigFile {
+    fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+
+        ConfigFile::parse(&contents)
+            .map_err(|err| format!("Failed to parse {}: {}", path.display(), err))
+    }
+
+    fn parse(contents: &str) -> Result<Self, String> {
+        toml::from_str(contents).map_err(|err| err.to_string())
+    }
+}
+
+/// `$xdg_var`, or the given folder of the home directory
+fn home_dir(xdg_var: &str, fallback: &str) -> Option<PathBuf> {
+    env::var_os(xdg_var)
+        .filter(|dir| ! dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(fallback)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_environment_overrides_the_file() {
+        let file = ConfigFile::parse("session = \"from-file\"\nbase_url = \"http://localhost:8080/\"").unwrap();
+        let config = Config::new(file, |name| (name == "AOC_SESSION").then(|| String::from(" from-env\n")));
+
+        assert_eq!(config.session.as_deref(), Some("from-env"));
+        assert_eq!(config.day_url(5), "http://localhost:8080/2022/day/5");
+    }
+
+    #[test]
+    fn everything_is_optional() {
+        let config = Config::parse("").unwrap();
+
+        assert_eq!(config.session, None);
+        assert!(config.session().is_err());
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert_eq!(config.history, config.cache_dir.join("2022/submissions.jsonl"));
+        assert!(Config::parse("token = \"abc\"").is_err());
+    }
+}
@@ -0,0 +1,74 @@
+#[path = "../day01/mod.rs"]
+pub mod day01;
+#[path = "../day02/mod.rs"]
+pub mod day02;
+#[path = "../day03/mod.rs"]
+pub mod day03;
+#[path = "../day04/mod.rs"]
+pub mod day04;
+#[path = "../day05/mod.rs"]
+pub mod day05;
+#[path = "../day06/mod.rs"]
+pub mod day06;
+#[path = "../day07/mod.rs"]
+pub mod day07;
+#[path = "../day08/mod.rs"]
+pub mod day08;
+#[path = "../day09/mod.rs"]
+pub mod day09;
+#[path = "../day10/mod.rs"]
+pub mod day10;
+#[path = "../day11/mod.rs"]
+pub mod day11;
+#[path = "../day12/mod.rs"]
+pub mod day12;
+#[path = "../day13/mod.rs"]
+pub mod day13;
+#[path = "../day14/mod.rs"]
+pub mod day14;
+#[path = "../day15/mod.rs"]
+pub mod day15;
+#[path = "../day16/mod.rs"]
+pub mod day16;
+#[path = "../day17/mod.rs"]
+pub mod day17;
+#[path = "../day18/mod.rs"]
+pub mod day18;
+#[path = "../day19/mod.rs"]
+pub mod day19;
+
+use crate::solution::DynSolution;
+
+/// Every implemented day, in order.
+pub static DAYS: &[(u8, &dyn DynSolution)] = &[
+    (1, &day01::Day),
+    (2, &day02::Day),
+    (3, &day03::Day),
+    (4, &day04::Day),
+    (5, &day05::Day),
+    (6, &day06::Day),
+    (7, &day07::Day),
+    (8, &day08::Day),
+    (9, &day09::Day),
+    (10, &day10::Day),
+    (11, &day11::Day),
+    (12, &day12::Day),
+    (13, &day13::Day),
+    (14, &day14::Day),
+    (15, &day15::Day),
+    (16, &day16::Day),
+    (17, &day17::Day),
+    (18, &day18::Day),
+    (19, &day19::Day),
+];
+
+pub fn get(day: u8) -> Option<&'static dyn DynSolution> {
+    DAYS.iter()
+        .find(|(d, _)| *d == day)
+        .map(|(_, s)| *s)
+}
+
+/// The folder that holds the solution and the input files of a day, eg: `day07`
+pub fn dir_name(day: u8) -> String {
+    format!("day{:02}", day)
+}
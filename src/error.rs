@@ -0,0 +1,192 @@
+use std::{error::Error, fmt, ops::RangeInclusive, str::FromStr};
+
+/// A problem in the puzzle input, along with where it is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1 based line number
+    pub line: usize,
+    /// 1 based column, counted in characters
+    pub column: usize,
+    /// The part of the input that couldn't be parsed, empty when something is missing
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error pointing at `text`, which has to be a slice of `input`
+    /// for the position to be found, otherwise the error points at the start of the input.
+    pub fn at(input: &str, text: &str, message: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&offset| offset <= input.len() && input.is_char_boundary(offset))
+            .unwrap_or(0);
+
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        ParseError {
+            line,
+            column,
+            text: String::from(text),
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error pointing at the end of the input, for things that are missing entirely
+    pub fn end_of_input(input: &str, message: impl Into<String>) -> Self {
+        ParseError::at(input, &input[input.len()..], message)
+    }
+
+    /// Moves an error that was created while parsing `part` on its own (eg: in a `FromStr`
+    /// implementation) to the position of `part` in `input`
+    pub fn within(self, input: &str, part: &str) -> Self {
+        let base = ParseError::at(input, part, "");
+
+        let (line, column) = if self.line == 1 {
+            (base.line, base.column + self.column - 1)
+        } else {
+            (base.line + self.line - 1, self.column)
+        };
+
+        ParseError { line, column, ..self }
+    }
+
+    /// A multi-line description of the error that quotes the offending line of the input, eg:
+    ///
+    /// ```text
+    /// error: invalid number
+    ///  --> day04/input:3:7
+    ///   |
+    /// 3 | 2-4,6x8
+    ///   |     ^^^
+    /// ```
+    pub fn diagnostic(&self, source_name: &str, input: &str) -> String {
+        let line_text = input.lines().nth(self.line - 1).unwrap_or("");
+        let gutter = " ".repeat(self.line.to_string().len());
+        let padding = " ".repeat(self.column - 1);
+        let underline = "^".repeat(self.text.chars().count().max(1));
+
+        format!(
+            "error: {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}",
+            self.message,
+            gutter, source_name, self.line, self.column,
+            gutter,
+            self.line, line_text,
+            gutter, padding, underline,
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+
+        if ! self.text.is_empty() {
+            write!(f, " (found {:?})", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a number out of `text`, which has to be a slice of `input`
+pub fn parse_number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(input, text, "invalid number"))
+}
+
+/// Parses a number out of `text` like [`parse_number`], which also has to be within `range`
+/// so that the solutions don't overflow on it
+pub fn parse_number_in<T: FromStr + PartialOrd + fmt::Display>(input: &str, text: &str, range: RangeInclusive<T>) -> Result<T, ParseError> {
+    let out_of_range = || ParseError::at(input, text, format!("expected a number from {} to {}", range.start(), range.end()));
+
+    // Digits that don't even fit in `T` are out of range too
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    let number = match text.parse() {
+        Ok(number) => number,
+        Err(_) if ! digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) => return Err(out_of_range()),
+        Err(_) => return Err(ParseError::at(input, text, "invalid number")),
+    };
+
+    if ! range.contains(&number) {
+        return Err(out_of_range());
+    }
+
+    Ok(number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1-2,3-4\n2-4,6x8\n";
+
+    #[test]
+    fn at_finds_the_line_and_column() {
+        let text = &INPUT[12..15];
+        let err = ParseError::at(INPUT, text, "invalid number");
+
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 5, "6x8"));
+    }
+
+    #[test]
+    fn at_falls_back_to_the_start_for_foreign_text() {
+        let err = ParseError::at(INPUT, "6x8", "invalid number");
+
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+
+    #[test]
+    fn end_of_input_points_after_the_last_line() {
+        let err = ParseError::end_of_input("ab\ncd", "missing");
+
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, ""));
+    }
+
+    #[test]
+    fn within_moves_errors_to_their_place_in_the_input() {
+        let line = &INPUT[8..15]; // 2-4,6x8
+        let err = parse_number::<i32>(line, &line[4..]).unwrap_err().within(INPUT, line);
+
+        assert_eq!((err.line, err.column), (2, 5));
+
+        let block = &INPUT[4..]; // Starts in the middle of the first line
+        let err = ParseError::at(block, &block[4..7], "a").within(INPUT, block);
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = ParseError::at(block, &block[1..2], "b").within(INPUT, block);
+        assert_eq!((err.line, err.column), (1, 6));
+    }
+
+    #[test]
+    fn parse_number_in_checks_the_range() {
+        assert_eq!(parse_number_in(INPUT, &INPUT[4..5], 1..=3), Ok(3));
+
+        let err = parse_number_in(INPUT, &INPUT[10..11], 0..=3).unwrap_err();
+        assert_eq!((err.line, err.column, err.message.as_str()), (2, 3, "expected a number from 0 to 3"));
+        assert_eq!(parse_number_in::<u8>(INPUT, &INPUT[12..15], 0..=3).unwrap_err().message, "invalid number");
+        assert_eq!(parse_number_in::<u8>("-99999", "-99999", 0..=3).unwrap_err().message, "expected a number from 0 to 3");
+    }
+
+    #[test]
+    fn diagnostic_underlines_the_text() {
+        let err = ParseError::at(INPUT, &INPUT[12..15], "invalid number");
+
+        assert_eq!(
+            err.diagnostic("day04/input", INPUT),
+            "error: invalid number\n --> day04/input:2:5\n  |\n2 | 2-4,6x8\n  |     ^^^",
+        );
+    }
+
+    #[test]
+    fn display_mentions_the_text() {
+        let err = ParseError::at(INPUT, &INPUT[12..15], "invalid number");
+
+        assert_eq!(err.to_string(), "line 2, column 5: invalid number (found \"6x8\")");
+    }
+}
@@ -0,0 +1,131 @@
+use std::{fs, path::{Path, PathBuf}};
+
+use crate::{config::{self, Config, YEAR}, days};
+
+/// Where `fetch` got the input from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin {
+    Cache,
+    Site,
+}
+
+/// Copies the puzzle input of a day to `dest`, it's only downloaded when it isn't in the cache yet.
+/// An existing `dest` is only overwritten when forced to
+pub fn fetch(config: &Config, day: u8, dest: &Path, force: bool) -> Result<Origin, String> {
+    if dest.exists() && ! force {
+        return Err(format!("{} already exists, --force overwrites it", dest.display()));
+    }
+
+    let cache_path = cache_path(config, day);
+
+    let (contents, origin) = match fs::read_to_string(&cache_path) {
+        Ok(contents) => (contents, Origin::Cache),
+        Err(_) => {
+            let contents = download(config, day)?;
+
+            write(&cache_path, &contents)?;
+            (contents, Origin::Site)
+        },
+    };
+
+    write(dest, &contents)?;
+    Ok(origin)
+}
+
+/// Where the input of a day is kept once it's downloaded, eg: `~/.cache/aoc/2022/day07.txt`
+pub fn cache_path(config: &Config, day: u8) -> PathBuf {
+    config.cache_dir.join(YEAR.to_string()).join(format!("{}.txt", days::dir_name(day)))
+}
+
+fn download(config: &Config, day: u8) -> Result<String, String> {
+    let url = format!("{}/input", config.day_url(day));
+
+    let response = config::agent()
+        .get(&url)
+        .set("Cookie", &config.cookie()?)
+        .call();
+
+    match response {
+        Ok(response) => response.into_string().map_err(|err| format!("Failed to read {}: {}", url, err)),
+        // The site answers 400 when the session is wrong and 404 before the puzzle unlocks
+        Err(ureq::Error::Status(status @ (400 | 401 | 403), _)) => {
+            Err(format!("Failed to fetch {}: {}, the session token might have expired", url, status))
+        },
+        Err(ureq::Error::Status(404, _)) => Err(format!("Failed to fetch {}: 404, the puzzle might not be unlocked yet", url)),
+        Err(err) => Err(format!("Failed to fetch {}: {}", url, err)),
+    }
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent().filter(|dir| ! dir.as_os_str().is_empty()) {
+        fs::create_dir_all(dir).map_err(|err| format!("Failed to create {}: {}", dir.display(), err))?;
+    }
+
+    fs::write(path, contents).map_err(|err| format!("Failed to write {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_support::serve;
+
+    use super::*;
+
+    fn config(name: &str, base_url: String) -> (Config, PathBuf) {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let config = Config {
+            session: Some(String::from("abc")),
+            base_url,
+            cache_dir: dir.join("cache"),
+            history: dir.join("history.jsonl"),
+        };
+
+        (config, dir)
+    }
+
+    #[test]
+    fn inputs_are_only_downloaded_once() {
+        let (url, requests) = serve(200, "1\n2\n");
+        let (config, dir) = config("once", url);
+
+        assert_eq!(fetch(&config, 7, &dir.join("day07/input"), false), Ok(Origin::Site));
+        assert_eq!(fetch(&config, 7, &dir.join("other/input"), false), Ok(Origin::Cache));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2022/day/7/input HTTP/1.1"));
+        assert!(requests[0].contains("\nCookie: session=abc"));
+
+        assert_eq!(fs::read_to_string(dir.join("day07/input")).unwrap(), "1\n2\n");
+        assert_eq!(fs::read_to_string(dir.join("cache/2022/day07.txt")).unwrap(), "1\n2\n");
+    }
+
+    #[test]
+    fn existing_inputs_are_only_overwritten_when_forced() {
+        let (url, _) = serve(200, "new");
+        let (config, dir) = config("force", url);
+        let dest = dir.join("day01/input");
+
+        write(&dest, "old").unwrap();
+
+        assert!(fetch(&config, 1, &dest, false).unwrap_err().contains("--force"));
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "old");
+
+        assert_eq!(fetch(&config, 1, &dest, true), Ok(Origin::Site));
+        assert_eq!(fs::read_to_string(&dest).unwrap(), "new");
+    }
+
+    #[test]
+    fn failed_downloads_arent_cached() {
+        let (url, _) = serve(400, "Please log in");
+        let (config, dir) = config("failed", url);
+
+        assert!(fetch(&config, 3, &dir.join("day03/input"), false).unwrap_err().contains("session token"));
+        assert!(! cache_path(&config, 3).exists());
+        assert!(! dir.join("day03/input").exists());
+
+        let config = Config { session: None, ..config };
+        assert!(fetch(&config, 3, &dir.join("day03/input"), false).unwrap_err().contains("AOC_SESSION"));
+    }
+}
@@ -0,0 +1,144 @@
+use std::ops::RangeInclusive;
+
+use crate::days::*;
+
+/// A random puzzle input generator of a day
+pub struct Generator {
+    pub day: u8,
+    /// What the size of the input is for the day
+    pub size: &'static str,
+    pub default_size: usize,
+    /// Writes an input of the given size that the day's `parse` accepts, the same seed always gives the same input
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+/// Every day's generator, in order.
+pub static GENERATORS: &[Generator] = &[
+    Generator { day: 1, size: "elves", default_size: 250, generate: day01::generate },
+    Generator { day: 2, size: "rounds", default_size: 2500, generate: day02::generate },
+    Generator { day: 3, size: "rucksacks, rounded up to groups of 3", default_size: 300, generate: day03::generate },
+    Generator { day: 4, size: "pairs of elves", default_size: 1000, generate: day04::generate },
+    Generator { day: 5, size: "stacks", default_size: 9, generate: day05::generate },
+    Generator { day: 6, size: "characters", default_size: 4096, generate: day06::generate },
+    Generator { day: 7, size: "files and directories", default_size: 300, generate: day07::generate },
+    Generator { day: 8, size: "trees along each side of the forest", default_size: 99, generate: day08::generate },
+    Generator { day: 9, size: "moves", default_size: 2000, generate: day09::generate },
+    Generator { day: 10, size: "largest addx value, the program always runs for the 240 cycles of the screen", default_size: 20, generate: day10::generate },
+    Generator { day: 11, size: "monkeys", default_size: 8, generate: day11::generate },
+    Generator { day: 12, size: "columns of the heightmap, at least 26", default_size: 100, generate: day12::generate },
+    Generator { day: 13, size: "pairs of packets", default_size: 150, generate: day13::generate },
+    Generator { day: 14, size: "rock paths", default_size: 150, generate: day14::generate },
+    Generator { day: 15, size: "bound of part 2, run it with --bound <size>", default_size: 4000000, generate: day15::generate },
+    Generator { day: 16, size: "valves with a flow rate", default_size: 15, generate: day16::generate },
+    Generator { day: 17, size: "jets", default_size: 10000, generate: day17::generate },
+    Generator { day: 18, size: "cubes", default_size: 2000, generate: day18::generate },
+    Generator { day: 19, size: "blueprints", default_size: 30, generate: day19::generate },
+];
+
+pub fn get(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+/// A small seeded random number generator (SplitMix64), so that the inputs of a seed never change
+/// along with the version of a dependency
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` has to be at least 1
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+
+        start + (self.next_u64() % (end - start + 1) as u64) as i64
+    }
+
+    /// True `percent` out of 100 times
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+
+    /// A random lowercase letter from `a` to the given one
+    pub fn letter(&mut self, last: char) -> char {
+        (b'a' + self.below((last as u8 - b'a') as usize + 1) as u8) as char
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{days, solution::Params};
+
+    use super::*;
+
+    #[test]
+    fn seeds_give_the_same_numbers() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.range(-3..=3)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        assert!(numbers(7).iter().all(|n| (-3..=3).contains(n)));
+    }
+
+    #[test]
+    fn every_day_has_a_generator() {
+        let days: Vec<u8> = days::DAYS.iter().map(|&(day, _)| day).collect();
+        let generators: Vec<u8> = GENERATORS.iter().map(|generator| generator.day).collect();
+
+        assert_eq!(days, generators);
+    }
+
+    #[test]
+    fn the_inputs_can_be_solved() {
+        // Small enough that all of them run quickly in a debug build
+        let sizes = [10, 40, 12, 40, 5, 500, 40, 15, 50, 20, 4, 30, 20, 15, 40, 6, 40, 100, 2];
+
+        for (generator, size) in GENERATORS.iter().zip(sizes) {
+            let solution = days::get(generator.day).unwrap();
+
+            for seed in 0..3 {
+                let input = (generator.generate)(&mut Rng::new(seed), size);
+                let parsed = solution.parse(&input).unwrap_or_else(|err| {
+                    panic!("Day {} seed {} doesn't parse: {}\n{}", generator.day, seed, err.message, input)
+                });
+
+                // Day 19 isn't finished
+                if generator.day == 19 {
+                    continue;
+                }
+
+                let params = Params { row: Some(size as i32 / 2), bound: Some(size as i32), ..Params::default() };
+                solution.part1(parsed.as_ref(), &params);
+                solution.part2(parsed.as_ref(), &params);
+            }
+        }
+    }
+}
@@ -0,0 +1,364 @@
+use std::{fmt, ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign}};
+
+/// Integer types that can be used as coordinates
+pub trait Coord:
+    Copy
+    + Ord
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> Self {
+                    <$t>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_coord!(i8, i16, i32, i64, isize);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: Coord> Point<T> {
+    pub const fn origin() -> Self {
+        Point { x: T::ZERO, y: T::ZERO }
+    }
+
+    pub fn manhattan_distance_to(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// Distance when diagonal moves cost the same as straight ones
+    pub fn chebyshev_distance_to(&self, other: &Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Clamps each axis to -1, 0 or 1, which turns a delta into a single step in the same direction
+    pub fn signum(&self) -> Self {
+        Point { x: self.x.signum(), y: self.y.signum() }
+    }
+
+    /// Up, right, down and left (y grows downwards)
+    pub fn neighbours4(&self) -> impl Iterator<Item = Self> {
+        let Point { x, y } = *self;
+        let one = T::ONE;
+
+        [
+            Point::new(x, y - one),
+            Point::new(x + one, y),
+            Point::new(x, y + one),
+            Point::new(x - one, y),
+        ].into_iter()
+    }
+
+    /// Same as [`Point::neighbours4`] plus the diagonals, clockwise starting from the top left
+    pub fn neighbours8(&self) -> impl Iterator<Item = Self> {
+        let Point { x, y } = *self;
+        let one = T::ONE;
+
+        [
+            Point::new(x - one, y - one),
+            Point::new(x, y - one),
+            Point::new(x + one, y - one),
+            Point::new(x + one, y),
+            Point::new(x + one, y + one),
+            Point::new(x, y + one),
+            Point::new(x - one, y + one),
+            Point::new(x - one, y),
+        ].into_iter()
+    }
+}
+
+impl<T: Coord> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Coord> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Coord> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Coord> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Coord> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: Coord> Point3<T> {
+    pub fn manhattan_distance_to(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// The 6 points that share a face with this one
+    pub fn neighbours6(&self) -> impl Iterator<Item = Self> {
+        let Point3 { x, y, z } = *self;
+        let one = T::ONE;
+
+        [
+            // Z
+            Point3::new(x, y, z - one),
+            Point3::new(x, y, z + one),
+
+            // X
+            Point3::new(x - one, y, z),
+            Point3::new(x + one, y, z),
+
+            // Y
+            Point3::new(x, y - one, z),
+            Point3::new(x, y + one, z),
+        ].into_iter()
+    }
+
+    /// Whether the point lies within the box spanned by `min` and `max` (inclusive)
+    pub fn is_within(&self, min: &Self, max: &Self) -> bool {
+        min.x <= self.x && self.x <= max.x
+        && min.y <= self.y && self.y <= max.y
+        && min.z <= self.z && self.z <= max.z
+    }
+}
+
+impl<T: Coord> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Point3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T: Coord> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// An inclusive range of coordinates, `Range::new(2, 4)` covers 2, 3 and 4
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Range<T = i32> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Coord> Range<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Range { start, end }
+    }
+
+    /// Creates a range between 2 values regardless of their order
+    pub fn between(a: T, b: T) -> Self {
+        Range { start: a.min(b), end: a.max(b) }
+    }
+
+    pub fn contains(&self, v: T) -> bool {
+        self.start <= v && v <= self.end
+    }
+
+    pub fn len(&self) -> T {
+        self.end - self.start + T::ONE
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+
+    /// Sorts the ranges and merges the ones that overlap or touch each other,
+    /// the result is sorted and has no gaps shorter than 1
+    pub fn merge(list: &mut [Self]) -> Vec<Self> {
+        list.sort_by_key(|x| x.start);
+
+        let Some((&first, rest)) = list.split_first() else {
+            return vec![];
+        };
+
+        let mut result: Vec<Self> = vec![];
+
+        let mut acc = first;
+        for &curr in rest {
+            if acc.end >= (curr.start - T::ONE) {
+                acc.end = acc.end.max(curr.end); // Extend acc
+            } else {
+                result.push(acc);
+                acc = curr;
+            }
+        }
+
+        result.push(acc);
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    fn ranges(list: &[(i32, i32)]) -> Vec<Range> {
+        list.iter().map(|&(start, end)| Range::new(start, end)).collect()
+    }
+
+    #[test]
+    fn merge_joins_overlapping_ranges() {
+        let mut list = ranges(&[(1, 5), (3, 8), (7, 10)]);
+
+        assert_eq!(Range::merge(&mut list), ranges(&[(1, 10)]));
+    }
+
+    #[test]
+    fn merge_joins_ranges_that_touch() {
+        let mut list = ranges(&[(1, 3), (4, 6)]);
+
+        assert_eq!(Range::merge(&mut list), ranges(&[(1, 6)]));
+    }
+
+    #[test]
+    fn merge_keeps_gaps() {
+        let mut list = ranges(&[(1, 2), (4, 5), (9, 9)]);
+
+        assert_eq!(Range::merge(&mut list), ranges(&[(1, 2), (4, 5), (9, 9)]));
+    }
+
+    #[test]
+    fn merge_swallows_contained_ranges() {
+        let mut list = ranges(&[(0, 20), (2, 3), (5, 19)]);
+
+        assert_eq!(Range::merge(&mut list), ranges(&[(0, 20)]));
+    }
+
+    #[test]
+    fn merge_sorts_its_input() {
+        let mut list = ranges(&[(12, 14), (-2, 2), (6, 10), (2, 4)]);
+
+        assert_eq!(Range::merge(&mut list), ranges(&[(-2, 4), (6, 10), (12, 14)]));
+    }
+
+    #[test]
+    fn merge_of_nothing() {
+        assert_eq!(Range::<i32>::merge(&mut []), vec![]);
+    }
+
+    #[test]
+    fn range_is_inclusive() {
+        let range = Range::between(4, 2);
+
+        assert_eq!(range, Range::new(2, 4));
+        assert_eq!(range.len(), 3);
+        assert!(range.contains(2) && range.contains(4));
+        assert!(! range.contains(5));
+    }
+
+    #[test]
+    fn point_distances() {
+        let a = Point::new(1, 1);
+        let b = Point::new(4, -1);
+
+        assert_eq!(a.manhattan_distance_to(&b), 5);
+        assert_eq!(a.chebyshev_distance_to(&b), 3);
+        assert_eq!((b - a).signum(), Point::new(1, -1));
+    }
+
+    /// The ranges of every run of consecutive numbers covered by the list
+    fn merge_brute_force(list: &[Range]) -> Vec<Range> {
+        let covered: BTreeSet<i32> = list.iter().flat_map(|range| range.start..=range.end).collect();
+        let mut result: Vec<Range> = vec![];
+
+        for n in covered {
+            match result.last_mut() {
+                Some(last) if last.end + 1 == n => last.end = n,
+                _ => result.push(Range::new(n, n)),
+            }
+        }
+
+        result
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn merge_matches_brute_force(list in vec((-30..30, 0..12).prop_map(|(start, len)| Range::new(start, start + len)), 0..12)) {
+            prop_assert_eq!(Range::merge(&mut list.clone()), merge_brute_force(&list));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::{fmt, ops::{Index, IndexMut}};
+
+use crate::geometry::Point;
+
+/// A rectangular grid of cells stored row by row, `(0, 0)` is the top left cell
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RaggedGridError {
+    pub row: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl fmt::Display for RaggedGridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "row {} has {} cells, expected {}", self.row + 1, self.found, self.expected)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self where T: Clone {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    /// Builds a grid out of rows that all have to be of the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, RaggedGridError> {
+        let width = rows.first().map_or(0, |xs| xs.len());
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (row, xs) in rows.into_iter().enumerate() {
+            if xs.len() != width {
+                return Err(RaggedGridError { row, expected: width, found: xs.len() });
+            }
+
+            cells.extend(xs);
+        }
+
+        Ok(Grid { width, height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the point is inside of the grid
+    pub fn contains(&self, point: Point) -> bool {
+        point.x >= 0 && point.y >= 0
+        && (point.x as usize) < self.width
+        && (point.y as usize) < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|i| &mut self.cells[i])
+    }
+
+    /// All of the points of the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i32, self.height as i32);
+
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0 so an empty grid gets a chunk size of 1, it has no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Finds the first point (row by row) that holds the value
+    pub fn position(&self, needle: &T) -> Option<Point> where T: PartialEq {
+        self.iter().find(|(_, c)| *c == needle).map(|(p, _)| p)
+    }
+
+    /// The orthogonal neighbours of a point that are inside of the grid
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbours4().filter(move |p| self.contains(*p))
+    }
+
+    /// The orthogonal and diagonal neighbours of a point that are inside of the grid
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbours8().filter(move |p| self.contains(*p))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        if ! self.contains(point) {
+            return None;
+        }
+
+        Some(point.y as usize * self.width + point.x as usize)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("Point {} is outside of the {}x{} grid", point, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("Point {} is outside of the {}x{} grid", point, width, height))
+    }
+}
+
+/// Prints the grid row by row without any separators between the cells
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+use std::{fs, io::{self, Read}, path::PathBuf};
+
+use crate::days;
+
+/// Where the puzzle input of a day is read from
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// The personal puzzle input at `dayXX/input`
+    #[default]
+    Puzzle,
+    /// The example from the puzzle description at `dayXX/exampleinput`
+    Example,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn is_example(&self) -> bool {
+        *self == InputSource::Example
+    }
+
+    /// Name of the file inside of the day's directory, for the inputs that live there
+    pub fn file_name(&self) -> Option<&'static str> {
+        match self {
+            InputSource::Puzzle => Some("input"),
+            InputSource::Example => Some("exampleinput"),
+            InputSource::File(_) | InputSource::Stdin => None,
+        }
+    }
+
+    /// The path that gets read for the given day, used in messages
+    pub fn describe(&self, day: u8) -> String {
+        match self {
+            InputSource::Puzzle => default_path(day, "input").display().to_string(),
+            InputSource::Example => default_path(day, "exampleinput").display().to_string(),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => String::from("<stdin>"),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> Result<String, String> {
+        let path = match self {
+            InputSource::Puzzle => default_path(day, "input"),
+            InputSource::Example => default_path(day, "exampleinput"),
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => {
+                let mut contents = String::new();
+
+                io::stdin()
+                    .read_to_string(&mut contents)
+                    .map_err(|err| format!("Failed to read the input from stdin: {}", err))?;
+
+                return Ok(contents);
+            },
+        };
+
+        fs::read_to_string(&path)
+            .map_err(|err| format!("Failed to read {}: {}", path.display(), err))
+    }
+}
+
+fn default_path(day: u8, file_name: &str) -> PathBuf {
+    PathBuf::from(days::dir_name(day)).join(file_name)
+}
+
+/// The input with `\r\n` line endings turned into `\n`, the whitespace at the end of each line removed and a single
+/// newline at the end, so that the days don't have to care about how the file was saved.
+/// The lines keep their numbers and their text keeps its columns, so errors still point at the same place
+pub fn normalize(input: &str) -> String {
+    let mut normalized = input
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<&str>>()
+        .join("\n");
+
+    normalized.truncate(normalized.trim_end_matches('\n').len());
+
+    if ! normalized.is_empty() {
+        normalized.push('\n');
+    }
+
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_turns_crlf_into_lf() {
+        assert_eq!(normalize("1000\r\n2000\r\n\r\n3000\r\n"), "1000\n2000\n\n3000\n");
+    }
+
+    #[test]
+    fn normalize_removes_trailing_whitespace() {
+        assert_eq!(normalize("A Y \nB X\t\r\n"), "A Y\nB X\n");
+    }
+
+    #[test]
+    fn normalize_keeps_leading_whitespace_and_blank_lines_in_between() {
+        assert_eq!(normalize("    [D]\n[N] [C]\n\n\nmove 1 from 2 to 1\n"), "    [D]\n[N] [C]\n\n\nmove 1 from 2 to 1\n");
+    }
+
+    #[test]
+    fn normalize_ends_with_a_single_newline() {
+        assert_eq!(normalize("mjqjpqmgbljsphdztnvjfqwrcgsmlb"), "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n");
+        assert_eq!(normalize("1\n2\n\n\n\r\n  \n"), "1\n2\n");
+        assert_eq!(normalize(" \r\n\n"), "");
+    }
+}
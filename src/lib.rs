@@ -0,0 +1,21 @@
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod config;
+pub mod days;
+pub mod error;
+pub mod fetch;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod parallel;
+pub mod render;
+pub mod scaffold;
+pub mod serve;
+pub mod solution;
+pub mod submit;
+#[cfg(test)]
+mod test_support;
+pub mod verify;
@@ -0,0 +1,7 @@
+use std::{env, process};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    process::exit(aoc2022::cli::main(&args));
+}
@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::bench::Stats;
+
+/// How the results of `aoc run` are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// `Part 1: ...` lines meant for people
+    #[default]
+    Text,
+    /// One JSON object per line and part, see [`Record`]
+    Json,
+}
+
+/// The result of running one part of a day, eg:
+///
+/// ```json
+/// {"day":1,"part":1,"answer":"24000","input":"day01/exampleinput","input_sha256":"...",
+///  "durations":{"parse":{"runs":1,"min_ns":4000,...},"part":{"runs":1,"min_ns":1000,...}}}
+/// ```
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer<'a>,
+    pub input: String,
+    pub input_sha256: String,
+    pub durations: Durations,
+}
+
+/// Multi-line answers (like the CRT of day 10) are split into their lines
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer<'a> {
+    Line(&'a str),
+    Lines(Vec<&'a str>),
+}
+
+impl<'a> Answer<'a> {
+    pub fn new(answer: &'a str) -> Self {
+        if ! answer.contains('\n') {
+            return Answer::Line(answer);
+        }
+
+        // The pictures start on their own line so that they line up when printed after `Part 2: `
+        Answer::Lines(answer.trim_matches('\n').lines().collect())
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Durations {
+    pub parse: Timings,
+    pub part: Timings,
+}
+
+/// Statistics of every run of a phase in nanoseconds, they're all the same for a single run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Timings {
+    pub runs: usize,
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: u128,
+    pub stddev_ns: u128,
+}
+
+impl Timings {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let stats = Stats::from_samples(samples)?;
+
+        Some(Timings {
+            runs: samples.len(),
+            min_ns: stats.min.as_nanos(),
+            median_ns: stats.median.as_nanos(),
+            mean_ns: stats.mean.as_nanos(),
+            stddev_ns: stats.stddev.as_nanos(),
+        })
+    }
+}
+
+impl Record<'_> {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Records only hold strings and numbers")
+    }
+}
+
+/// Hex encoded SHA-256 of the input, to tell which input an answer belongs to without storing it
+pub fn input_hash(contents: &str) -> String {
+    Sha256::digest(contents.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_line_answers_stay_strings() {
+        assert_eq!(Answer::new("24000"), Answer::Line("24000"));
+        assert_eq!(serde_json::to_string(&Answer::new("24000")).unwrap(), r#""24000""#);
+    }
+
+    #[test]
+    fn multi_line_answers_become_arrays() {
+        let answer = Answer::new("\n##..\n.##.\n");
+
+        assert_eq!(answer, Answer::Lines(vec!["##..", ".##."]));
+        assert_eq!(serde_json::to_string(&answer).unwrap(), r###"["##..",".##."]"###);
+    }
+
+    #[test]
+    fn timings_of_a_single_run() {
+        let timings = Timings::from_samples(&[Duration::from_nanos(1500)]).unwrap();
+
+        assert_eq!(timings, Timings { runs: 1, min_ns: 1500, median_ns: 1500, mean_ns: 1500, stddev_ns: 0 });
+        assert_eq!(Timings::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn input_hash_is_hex_sha256() {
+        assert_eq!(input_hash(""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+    }
+}
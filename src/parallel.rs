@@ -0,0 +1,147 @@
+use std::{any::Any, panic::{self, AssertUnwindSafe}, sync::{atomic::{AtomicUsize, Ordering}, Mutex}, thread, time::{Duration, Instant}};
+
+use crate::{days, input::InputSource, solution::Params};
+
+/// The outcome of one part of a day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRun {
+    pub day: u8,
+    pub part: u8,
+    /// The answer, or why there isn't one: the input couldn't be read or parsed, or the part panicked
+    pub answer: Result<String, String>,
+    /// How long the part took, without parsing
+    pub time: Duration,
+}
+
+/// Runs both parts of the days on `jobs` threads, each part parses its own copy of the input.
+/// A part that panics only fails itself, the results are sorted by day and part
+pub fn run(days: &[u8], jobs: usize, source: &InputSource, params: &Params) -> Vec<PartRun> {
+    let tasks: Vec<(u8, u8)> = days.iter().flat_map(|&day| [(day, 1), (day, 2)]).collect();
+
+    run_tasks(&tasks, jobs, |day, part| run_part(day, part, source, params))
+}
+
+fn run_tasks(tasks: &[(u8, u8)], jobs: usize, run_part: impl Fn(u8, u8) -> PartRun + Sync) -> Vec<PartRun> {
+    let next = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::new());
+
+    // The default hook still prints where a part panicked, before the table that reports it
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(&(day, part)) = tasks.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let run = panic::catch_unwind(AssertUnwindSafe(|| run_part(day, part))).unwrap_or_else(|payload| {
+                        let answer = Err(format!("panicked: {}", panic_message(payload.as_ref())));
+                        PartRun { day, part, answer, time: Duration::ZERO }
+                    });
+
+                    runs.lock().unwrap_or_else(|err| err.into_inner()).push(run);
+                }
+            });
+        }
+    });
+
+    let mut runs = runs.into_inner().unwrap_or_else(|err| err.into_inner());
+    runs.sort_by_key(|run| (run.day, run.part));
+    runs
+}
+
+fn run_part(day: u8, part: u8, source: &InputSource, params: &Params) -> PartRun {
+    let failed = |err: String| PartRun { day, part, answer: Err(err), time: Duration::ZERO };
+
+    let Some(solution) = days::get(day) else {
+        return failed(format!("Day {} is not implemented", day));
+    };
+
+    let contents = match source.read(day) {
+        Ok(contents) => contents,
+        Err(err) => return failed(err),
+    };
+
+    let parsed = match solution.parse(&contents) {
+        Ok(parsed) => parsed,
+        Err(err) => return failed(err.diagnostic(&source.describe(day), &contents)),
+    };
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => solution.part1(parsed.as_ref(), params),
+        _ => solution.part2(parsed.as_ref(), params),
+    };
+
+    PartRun { day, part, answer: Ok(answer), time: start.elapsed() }
+}
+
+/// The message that a part panicked with, from what `catch_unwind` caught
+pub fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload.downcast_ref::<&str>().copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("Box<dyn Any>")
+}
+
+/// Prints the runs as a table with the total time of the parts, fails if any of them failed
+pub fn report(runs: &[PartRun], elapsed: Duration, jobs: usize) -> Result<(), String> {
+    // Multi-line answers and errors are printed under their row, so they don't count towards the width
+    let width = runs.iter()
+        .filter_map(|run| run.answer.as_ref().ok().filter(|answer| ! answer.contains('\n')))
+        .map(|answer| answer.chars().count())
+        .fold(6, usize::max);
+
+    println!("{:>3}  {:>4}  {:<width$}  {:>10}", "Day", "Part", "Answer", "Time");
+
+    for run in runs {
+        let (answer, below) = match &run.answer {
+            Ok(answer) if answer.contains('\n') => ("", answer.trim_matches('\n')),
+            Ok(answer) => (answer.as_str(), ""),
+            Err(err) => ("error", err.as_str()),
+        };
+
+        println!("{:>3}  {:>4}  {:<width$}  {:>10}", run.day, run.part, answer, format!("{:.2?}", run.time));
+
+        if ! below.is_empty() {
+            println!("{}", below);
+        }
+    }
+
+    let total: Duration = runs.iter().map(|run| run.time).sum();
+    println!("{:<width$}  {:>10}", "Total", format!("{:.2?}", total), width = width + 11);
+    println!("Took {:.2?} on {} thread(s)", elapsed, jobs);
+
+    match runs.iter().filter(|run| run.answer.is_err()).count() {
+        0 => Ok(()),
+        failed => Err(format!("{} part(s) failed", failed)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn the_runs_are_sorted() {
+        let runs = run(&[6, 2, 1], 4, &InputSource::Example, &Params { example: true, ..Params::default() });
+        let answers: Vec<(u8, u8, &str)> = runs.iter().map(|run| (run.day, run.part, run.answer.as_deref().unwrap())).collect();
+
+        assert_eq!(answers, [(1, 1, "24000"), (1, 2, "45000"), (2, 1, "15"), (2, 2, "12"), (6, 1, "11"), (6, 2, "26")]);
+    }
+
+    #[test]
+    fn failures_are_isolated() {
+        let runs = run(&[1, 30], 2, &InputSource::File(PathBuf::from("day02/exampleinput")), &Params::default());
+
+        assert!(runs[0].answer.as_ref().unwrap_err().contains("day02/exampleinput"));
+        assert!(runs[3].answer.as_ref().unwrap_err().contains("not implemented"));
+        assert!(report(&runs, Duration::ZERO, 2).is_err());
+
+        let tasks = [(1, 1), (1, 2), (2, 1), (2, 2)];
+        let runs = run_tasks(&tasks, 2, |day, part| match (day, part) {
+            (1, 2) => panic!("Unfinished"),
+            _ => PartRun { day, part, answer: Ok(String::from("42")), time: Duration::ZERO },
+        });
+
+        let answers: Vec<Result<&str, &str>> = runs.iter().map(|run| run.answer.as_deref().map_err(String::as_str)).collect();
+        assert_eq!(answers, [Ok("42"), Err("panicked: Unfinished"), Ok("42"), Ok("42")]);
+    }
+}
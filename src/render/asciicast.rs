@@ -0,0 +1,149 @@
+//! Recordings as terminal output with timestamps, see https://docs.asciinema.org/manual/asciicast/v2/
+
+use std::{fmt::Write as _, io::{self, Write}, time::Duration};
+
+use serde::Serialize;
+
+use crate::geometry::Point;
+
+use super::{Cell, Color, Patch, Recording, Style};
+
+#[derive(Serialize)]
+struct Header<'a> {
+    version: u8,
+    width: usize,
+    height: usize,
+    title: &'a str,
+}
+
+pub fn write(recording: &Recording, out: &mut impl Write, title: &str) -> io::Result<()> {
+    let (width, height) = recording.size();
+    let header = Header { version: 2, width, height, title };
+    writeln!(out, "{}", serde_json::to_string(&header)?)?;
+
+    let mut clock = Duration::ZERO;
+
+    for (i, step) in recording.steps().iter().enumerate() {
+        let output = match &step.patch {
+            Patch::Full(frame) => {
+                let cells = frame.rows()
+                    .enumerate()
+                    .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, &cell)| (Point::new(x as i32, y as i32), cell)))
+                    .filter(|(_, cell)| *cell != Cell::default());
+
+                // The first frame also hides the cursor
+                let clear = if i == 0 { "\x1b[?25l\x1b[2J" } else { "\x1b[0m\x1b[2J" };
+
+                format!("{}{}", clear, escape_codes(cells))
+            },
+            Patch::Cells(cells) => escape_codes(cells.iter().copied()),
+        };
+
+        writeln!(out, "{}", serde_json::to_string(&(clock.as_secs_f64(), "o", output))?)?;
+        clock += step.duration;
+    }
+
+    // The recording ends with its last event, this keeps the last frame up for as long as it should be
+    writeln!(out, "{}", serde_json::to_string(&(clock.as_secs_f64(), "o", ""))?)
+}
+
+/// The escape codes that draw the cells, which have to be ordered row by row
+fn escape_codes(cells: impl Iterator<Item = (Point, Cell)>) -> String {
+    let mut output = String::new();
+    // Where the terminal's cursor is, writing a character moves it one to the right
+    let mut cursor = None;
+    let mut style = None;
+
+    for (Point { x, y }, cell) in cells {
+        if cursor != Some((x, y)) {
+            write!(output, "\x1b[{};{}H", y + 1, x + 1).unwrap();
+        }
+
+        if style != Some(cell.style) {
+            write!(output, "\x1b[{};{}m", fg_code(cell.style), bg_code(cell.style)).unwrap();
+            style = Some(cell.style);
+        }
+
+        output.push(cell.ch);
+        cursor = Some((x + 1, y));
+    }
+
+    output
+}
+
+fn fg_code(style: Style) -> u8 {
+    match color_offset(style.fg) {
+        Some(offset) => 30 + offset,
+        None => 39,
+    }
+}
+
+fn bg_code(style: Style) -> u8 {
+    match color_offset(style.bg) {
+        Some(offset) => 40 + offset,
+        None => 49,
+    }
+}
+
+/// The number of the colour in the 8 colour SGR codes, `None` for the terminal's default
+fn color_offset(color: Color) -> Option<u8> {
+    match color {
+        Color::Default => None,
+        Color::Black => Some(0),
+        Color::Red => Some(1),
+        Color::Green => Some(2),
+        Color::Yellow => Some(3),
+        Color::Blue => Some(4),
+        Color::Magenta => Some(5),
+        Color::Cyan => Some(6),
+        Color::White => Some(7),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::render::Frame;
+
+    use super::*;
+
+    fn cell(ch: char) -> Cell {
+        Cell { ch, ..Cell::default() }
+    }
+
+    #[test]
+    fn cursor_moves_are_only_written_between_gaps() {
+        let cells = [(Point::new(0, 0), cell('x')), (Point::new(1, 0), cell('y')), (Point::new(1, 1), cell('z'))];
+
+        assert_eq!(escape_codes(cells.into_iter()), "\x1b[1;1H\x1b[39;49mxy\x1b[2;2Hz");
+    }
+
+    #[test]
+    fn styles_become_sgr_codes() {
+        let cells = [(Point::new(1, 0), Cell { ch: 'o', style: Style::new(Color::Black, Color::Green) })];
+
+        assert_eq!(escape_codes(cells.into_iter()), "\x1b[1;2H\x1b[30;42mo");
+    }
+
+    #[test]
+    fn recordings_are_asciicast_v2() {
+        let mut recording = Recording::new();
+
+        for (text, millis) in [("a", 500), ("b", 1000)] {
+            let mut frame = Frame::new(3, 2);
+            frame.text(Point::new(0, 0), text);
+            recording.push(&frame, Duration::from_millis(millis));
+        }
+
+        let mut out = Vec::new();
+        write(&recording, &mut out, "Day 1").unwrap();
+
+        let expected = [
+            r#"{"version":2,"width":3,"height":2,"title":"Day 1"}"#,
+            r#"[0.0,"o","\u001b[?25l\u001b[2J\u001b[1;1H\u001b[39;49ma"]"#,
+            r#"[0.5,"o","\u001b[1;1H\u001b[39;49mb"]"#,
+            r#"[1.5,"o",""]"#,
+        ];
+
+        assert_eq!(String::from_utf8(out).unwrap(), expected.join("\n") + "\n");
+    }
+}
@@ -0,0 +1,172 @@
+use crate::geometry::Point;
+
+use super::Frame;
+
+/// A rectangle of cells, `min` is the top left one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rect {
+    pub min: Point,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub fn new(min: Point, width: usize, height: usize) -> Self {
+        Rect { min, width, height }
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        let Point { x, y } = point - self.min;
+
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    /// An empty frame of the size of the rect
+    pub fn frame(&self) -> Frame {
+        Frame::new(self.width, self.height)
+    }
+
+    /// Where a point of the world ends up in the frame of the rect
+    pub fn to_frame(&self, point: Point) -> Point {
+        point - self.min
+    }
+}
+
+/// A step of a visualization that might not fit in what shows it,
+/// the renderers decide which part of the world gets drawn
+pub struct Scene<'a> {
+    /// Everything that there is to see
+    pub world: Rect,
+    /// Where the action is, the camera keeps it in sight
+    pub focus: Point,
+    /// Draws the given part of the world, `None` when there's nothing worth showing in it
+    pub draw: &'a dyn Fn(Rect) -> Option<Frame>,
+}
+
+impl Scene<'_> {
+    pub fn frame(&self, view: Rect) -> Option<Frame> {
+        (self.draw)(view)
+    }
+}
+
+/// Picks the part of a world that fits on the screen. It follows the focus of the scenes,
+/// and stays where it's put once it's panned until it's told to follow again
+#[derive(Debug, Default)]
+pub struct Camera {
+    /// The top left of the last view
+    position: Option<Point>,
+    panned: bool,
+}
+
+impl Camera {
+    pub fn new() -> Self {
+        Camera::default()
+    }
+
+    /// The part of the world that fits in `size` (width, height)
+    pub fn view(&mut self, world: Rect, (width, height): (usize, usize), focus: Point) -> Rect {
+        let (width, height) = (width.min(world.width), height.min(world.height));
+
+        let mut min = self.position.unwrap_or(focus - Point::new(width as i32 / 2, height as i32 / 2));
+
+        if ! self.panned {
+            min = Point::new(follow(min.x, focus.x, width), follow(min.y, focus.y, height));
+        }
+
+        let max = world.min + Point::new((world.width - width) as i32, (world.height - height) as i32);
+        let min = Point::new(min.x.clamp(world.min.x, max.x), min.y.clamp(world.min.y, max.y));
+
+        self.position = Some(min);
+        Rect::new(min, width, height)
+    }
+
+    pub fn pan(&mut self, delta: Point) {
+        if let Some(position) = &mut self.position {
+            *position += delta;
+        }
+
+        self.panned = true;
+    }
+
+    pub fn follow(&mut self) {
+        self.panned = false;
+    }
+}
+
+/// Moves `start`, the first shown cell of an axis, so that `focus` is at least a quarter of `size` away from the edges
+fn follow(start: i32, focus: i32, size: usize) -> i32 {
+    let (size, margin) = (size as i32, size as i32 / 4);
+
+    if focus < start + margin {
+        focus - margin
+    } else if focus >= start + size - margin {
+        focus - size + margin + 1
+    } else {
+        start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORLD: Rect = Rect { min: Point::new(-50, -50), width: 100, height: 100 };
+
+    #[test]
+    fn small_worlds_are_shown_whole() {
+        let mut camera = Camera::new();
+        let world = Rect::new(Point::new(3, 4), 40, 6);
+
+        assert_eq!(camera.view(world, (80, 24), Point::new(20, 7)), world);
+    }
+
+    #[test]
+    fn the_camera_starts_centered_on_the_focus() {
+        let mut camera = Camera::new();
+
+        assert_eq!(camera.view(WORLD, (20, 10), Point::new(0, 0)), Rect::new(Point::new(-10, -5), 20, 10));
+    }
+
+    #[test]
+    fn the_camera_only_moves_when_the_focus_gets_close_to_the_edges() {
+        let mut camera = Camera::new();
+        camera.view(WORLD, (20, 10), Point::new(0, 0));
+
+        assert_eq!(camera.view(WORLD, (20, 10), Point::new(4, 1)).min, Point::new(-10, -5));
+        assert_eq!(camera.view(WORLD, (20, 10), Point::new(6, 1)).min, Point::new(-8, -5));
+        assert_eq!(camera.view(WORLD, (20, 10), Point::new(6, -5)).min, Point::new(-8, -7));
+    }
+
+    #[test]
+    fn the_view_stays_inside_of_the_world() {
+        let mut camera = Camera::new();
+
+        assert_eq!(camera.view(WORLD, (20, 10), Point::new(49, -50)).min, Point::new(30, -50));
+
+        camera.pan(Point::new(100, 0));
+        assert_eq!(camera.view(WORLD, (20, 10), Point::new(49, -50)).min, Point::new(30, -50));
+    }
+
+    #[test]
+    fn panning_stops_following_until_asked_to() {
+        let mut camera = Camera::new();
+        camera.view(WORLD, (20, 10), Point::new(0, 0));
+
+        camera.pan(Point::new(-4, 2));
+        assert_eq!(camera.view(WORLD, (20, 10), Point::new(30, 30)).min, Point::new(-14, -3));
+
+        camera.follow();
+        assert_eq!(camera.view(WORLD, (20, 10), Point::new(30, 30)).min, Point::new(16, 23));
+    }
+
+    #[test]
+    fn rects_map_the_world_to_their_frame() {
+        let view = Rect::new(Point::new(-2, 3), 4, 2);
+
+        assert!(view.contains(Point::new(-2, 3)));
+        assert!(view.contains(Point::new(1, 4)));
+        assert!(! view.contains(Point::new(2, 4)));
+        assert!(! view.contains(Point::new(0, 5)));
+        assert_eq!(view.to_frame(Point::new(0, 4)), Point::new(2, 1));
+    }
+}
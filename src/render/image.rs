@@ -0,0 +1,400 @@
+//! Recordings as pictures, each cell becomes a square of pixels in the colour that the palette gives it
+
+use std::{collections::{BTreeMap, HashMap}, fs::{self, File}, io::{BufWriter, Write}, path::{Path, PathBuf}, time::Duration};
+
+use serde::Deserialize;
+
+use crate::geometry::Point;
+
+use super::{Cell, Color, Frame, Recording};
+
+/// Most viewers slow down the frames of a GIF that are shorter than this to 100ms,
+/// the shorter frames of a recording are merged into the ones after them instead
+const MIN_GIF_FRAME: Duration = Duration::from_millis(20);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Rgb(pub [u8; 3]);
+
+impl TryFrom<String> for Rgb {
+    type Error = String;
+
+    /// Parses `#rrggbb`
+    fn try_from(hex: String) -> Result<Self, Self::Error> {
+        let invalid = || format!("invalid colour {:?}, expected #rrggbb", hex);
+        let digits = hex.strip_prefix('#').filter(|d| d.len() == 6 && d.is_ascii()).ok_or_else(invalid)?;
+
+        let channel = |i: usize| u8::from_str_radix(&digits[i..(i + 2)], 16).map_err(|_| invalid());
+
+        Ok(Rgb([channel(0)?, channel(2)?, channel(4)?]))
+    }
+}
+
+/// Which colour each cell gets, in this order:
+/// the background colour of its style, the foreground colour of its style, the colour of its character,
+/// and otherwise `background` for spaces and `foreground` for everything else
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Palette {
+    pub background: Rgb,
+    pub foreground: Rgb,
+    pub chars: HashMap<char, Rgb>,
+    pub colors: HashMap<Color, Rgb>,
+}
+
+/// A palette file only has to list what it changes, eg:
+///
+/// ```toml
+/// background = "#000000"
+///
+/// [chars]
+/// "o" = "#ffd700"
+///
+/// [colors]
+/// red = "#ff8000"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PaletteFile {
+    background: Option<Rgb>,
+    foreground: Option<Rgb>,
+    #[serde(default)]
+    chars: BTreeMap<String, Rgb>,
+    #[serde(default)]
+    colors: BTreeMap<Color, Rgb>,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        let chars = [
+            ('#', Rgb([0x80, 0x80, 0x80])), // Rocks, and the positions that the rope's tail visited
+            ('|', Rgb([0x80, 0x80, 0x80])),
+            ('-', Rgb([0x80, 0x80, 0x80])),
+            ('+', Rgb([0xff, 0xff, 0xff])),
+            ('o', Rgb([0xe8, 0xc0, 0x60])), // Sand
+            ('@', Rgb([0x00, 0xcc, 0x00])), // The falling rock, and the lit pixels of the CRT
+            ('.', Rgb([0x20, 0x20, 0x40])),
+        ];
+
+        // The heights of day 12 go from a dark green to white
+        let heights = ('a'..='z').map(|c| {
+            let t = (c as u32 - 'a' as u32) as f64 / 25.0;
+            let channel = |from: f64, to: f64| (from + (to - from) * t).round() as u8;
+
+            (c, Rgb([channel(20.0, 240.0), channel(60.0, 250.0), channel(20.0, 240.0)]))
+        });
+
+        let colors = [
+            (Color::Black, Rgb([0x00, 0x00, 0x00])),
+            (Color::Red, Rgb([0xff, 0x40, 0x40])),
+            (Color::Green, Rgb([0x00, 0xcc, 0x00])),
+            (Color::Yellow, Rgb([0xff, 0xff, 0x66])),
+            (Color::Blue, Rgb([0x40, 0x60, 0xff])),
+            (Color::Magenta, Rgb([0xff, 0x40, 0xff])),
+            (Color::Cyan, Rgb([0x40, 0xff, 0xff])),
+            (Color::White, Rgb([0xff, 0xff, 0xff])),
+        ];
+
+        Palette {
+            background: Rgb([0x0f, 0x0f, 0x23]),
+            foreground: Rgb([0xcc, 0xcc, 0xcc]),
+            chars: chars.into_iter().chain(heights).collect(),
+            colors: colors.into_iter().collect(),
+        }
+    }
+}
+
+impl Palette {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+
+        Palette::parse(&contents)
+            .map_err(|err| format!("Failed to parse {}: {}", path.display(), err))
+    }
+
+    /// The default palette with the changes of a palette file
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let file: PaletteFile = toml::from_str(contents).map_err(|err| err.to_string())?;
+        let mut palette = Palette::default();
+
+        palette.background = file.background.unwrap_or(palette.background);
+        palette.foreground = file.foreground.unwrap_or(palette.foreground);
+        palette.colors.extend(file.colors);
+
+        for (key, rgb) in file.chars {
+            let mut chars = key.chars();
+
+            match (chars.next(), chars.next()) {
+                (Some(ch), None) => palette.chars.insert(ch, rgb),
+                _ => return Err(format!("{:?} in [chars] has to be a single character", key)),
+            };
+        }
+
+        Ok(palette)
+    }
+
+    pub fn color(&self, cell: Cell) -> Rgb {
+        let style_color = |color: Color| (color != Color::Default).then(|| self.colors.get(&color)).flatten();
+
+        style_color(cell.style.bg)
+            .or_else(|| style_color(cell.style.fg))
+            .or_else(|| self.chars.get(&cell.ch))
+            .copied()
+            .unwrap_or(if cell.ch == ' ' { self.background } else { self.foreground })
+    }
+
+    /// Every colour that the palette can give a cell
+    fn rgbs(&self) -> Vec<Rgb> {
+        let mut rgbs = vec![self.background, self.foreground];
+        rgbs.extend(self.chars.values().chain(self.colors.values()));
+        rgbs.sort_by_key(|rgb| rgb.0);
+        rgbs.dedup();
+        rgbs
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageOptions {
+    /// The width and height in pixels of each cell
+    pub cell_size: u32,
+    pub palette: Palette,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        ImageOptions { cell_size: 4, palette: Palette::default() }
+    }
+}
+
+/// The cells of `frame` inside of the rectangle, turned into pixels
+fn pixels<T: Copy>(frame: &Frame, (left, top, width, height): (usize, usize, usize, usize), cell_size: usize, pixel: impl Fn(Cell) -> T) -> Vec<T> {
+    let mut pixels = Vec::with_capacity(width * height * cell_size * cell_size);
+
+    for y in top..(top + height) {
+        let row: Vec<T> = (left..(left + width))
+            .map(|x| pixel(frame.get(Point::new(x as i32, y as i32)).unwrap_or_default()))
+            .collect();
+
+        for _ in 0..cell_size {
+            for &p in &row {
+                pixels.extend(std::iter::repeat_n(p, cell_size));
+            }
+        }
+    }
+
+    pixels
+}
+
+/// The smallest rectangle (left, top, width, height) around the cells that differ between the frames
+fn changed_area(from: &Frame, to: &Frame, (width, height): (usize, usize)) -> Option<(usize, usize, usize, usize)> {
+    let mut area: Option<(usize, usize, usize, usize)> = None;
+
+    for y in 0..height {
+        for x in 0..width {
+            let point = Point::new(x as i32, y as i32);
+
+            if from.get(point).unwrap_or_default() == to.get(point).unwrap_or_default() {
+                continue;
+            }
+
+            let (x0, y0, x1, y1) = area.map_or((x, y, x, y), |(x0, y0, x1, y1)| (x0.min(x), y0.min(y), x1.max(x), y1.max(y)));
+            area = Some((x0, y0, x1, y1));
+        }
+    }
+
+    area.map(|(x0, y0, x1, y1)| (x0, y0, x1 - x0 + 1, y1 - y0 + 1))
+}
+
+pub fn write_gif(recording: &Recording, out: impl Write, options: &ImageOptions) -> Result<(), String> {
+    let cell_size = options.cell_size as usize;
+    let size = recording.size();
+    let too_big = || format!("A {}x{} recording doesn't fit in a GIF with {} pixels per cell", size.0, size.1, cell_size);
+    let width = u16::try_from(size.0 * cell_size).map_err(|_| too_big())?;
+    let height = u16::try_from(size.1 * cell_size).map_err(|_| too_big())?;
+
+    let rgbs = options.palette.rgbs();
+
+    if rgbs.len() > 256 {
+        return Err(format!("A GIF can only hold 256 colours, the palette has {}", rgbs.len()));
+    }
+
+    let global_palette: Vec<u8> = rgbs.iter().flat_map(|rgb| rgb.0).collect();
+    let index = |cell: Cell| {
+        let rgb = options.palette.color(cell);
+        rgbs.binary_search_by_key(&rgb.0, |rgb| rgb.0).unwrap_or(0) as u8
+    };
+
+    let mut encoder = gif::Encoder::new(out, width, height, &global_palette).map_err(|err| err.to_string())?;
+    encoder.set_repeat(gif::Repeat::Infinite).map_err(|err| err.to_string())?;
+
+    // What the GIF shows so far, and for how long the frames since then have been up
+    let mut shown: Option<Frame> = None;
+    let mut elapsed = Duration::ZERO;
+    let last = recording.steps().len().saturating_sub(1);
+    let mut i = 0;
+
+    recording.replay(|frame, step| {
+        elapsed += step.duration;
+        i += 1;
+
+        if elapsed < MIN_GIF_FRAME && i <= last {
+            return Ok(());
+        }
+
+        let area = match &shown {
+            Some(shown) => changed_area(shown, frame, size),
+            None => Some((0, 0, size.0, size.1)),
+        };
+
+        // An unchanged frame adds one pixel with the same colour, to keep the timing
+        let (left, top, w, h) = area.unwrap_or((0, 0, 1, 1));
+
+        let mut gif_frame = gif::Frame::from_indexed_pixels(
+            (w * cell_size) as u16,
+            (h * cell_size) as u16,
+            pixels(frame, (left, top, w, h), cell_size, index),
+            None,
+        );
+
+        gif_frame.left = (left * cell_size) as u16;
+        gif_frame.top = (top * cell_size) as u16;
+        gif_frame.delay = (elapsed.as_millis() / 10).clamp(2, u16::MAX as u128) as u16;
+
+        encoder.write_frame(&gif_frame).map_err(|err| err.to_string())?;
+
+        shown = Some(frame.clone());
+        elapsed = Duration::ZERO;
+        Ok(())
+    })
+}
+
+/// Writes every frame to its own file next to `path`, numbered from 1
+pub fn write_pngs(recording: &Recording, path: &Path, options: &ImageOptions) -> Result<(), String> {
+    let cell_size = options.cell_size as usize;
+    let size = recording.size();
+    let mut number = 0;
+
+    recording.replay(|frame, _| {
+        number += 1;
+        let path = numbered(path, number);
+        let failed = |err: &dyn std::fmt::Display| format!("Failed to write {}: {}", path.display(), err);
+
+        let file = File::create(&path).map_err(|err| failed(&err))?;
+        let mut encoder = png::Encoder::new(BufWriter::new(file), (size.0 * cell_size) as u32, (size.1 * cell_size) as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        let data: Vec<u8> = pixels(frame, (0, 0, size.0, size.1), cell_size, |cell| options.palette.color(cell))
+            .into_iter()
+            .flat_map(|rgb| rgb.0)
+            .collect();
+
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&data))
+            .map_err(|err| failed(&err))
+    })
+}
+
+/// `frames/sand.png` becomes `frames/sand-00001.png`
+fn numbered(path: &Path, number: usize) -> PathBuf {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("frame");
+
+    path.with_file_name(format!("{}-{:05}.png", stem, number))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colours_are_parsed_from_hex() {
+        assert_eq!(Rgb::try_from(String::from("#ff8000")), Ok(Rgb([255, 128, 0])));
+        assert!(Rgb::try_from(String::from("ff8000")).is_err());
+        assert!(Rgb::try_from(String::from("#ff80")).is_err());
+        assert!(Rgb::try_from(String::from("#gg8000")).is_err());
+    }
+
+    #[test]
+    fn styles_win_over_characters() {
+        let palette = Palette::default();
+        let sand = Cell { ch: 'o', ..Cell::default() };
+        let falling = Cell { ch: 'o', style: crate::render::Style::fg(Color::Red) };
+
+        assert_eq!(palette.color(sand), palette.chars[&'o']);
+        assert_eq!(palette.color(falling), palette.colors[&Color::Red]);
+        assert_eq!(palette.color(Cell::default()), palette.background);
+        assert_eq!(palette.color(Cell { ch: '9', ..Cell::default() }), palette.foreground);
+    }
+
+    #[test]
+    fn palette_files_change_the_defaults() {
+        let palette = Palette::parse("background = \"#000000\"\n[chars]\n\"o\" = \"#ffd700\"\n[colors]\nred = \"#ff8000\"\n").unwrap();
+
+        assert_eq!(palette.background, Rgb([0, 0, 0]));
+        assert_eq!(palette.chars[&'o'], Rgb([0xff, 0xd7, 0x00]));
+        assert_eq!(palette.chars[&'#'], Palette::default().chars[&'#']);
+        assert_eq!(palette.colors[&Color::Red], Rgb([0xff, 0x80, 0x00]));
+
+        assert!(Palette::parse("[chars]\n\"ab\" = \"#000000\"\n").is_err());
+        assert!(Palette::parse("border = \"#000000\"\n").is_err());
+    }
+
+    #[test]
+    fn only_the_changed_area_is_redrawn() {
+        let mut from = Frame::new(4, 3);
+        let mut to = from.clone();
+
+        assert_eq!(changed_area(&from, &to, (4, 3)), None);
+
+        to.put(Point::new(1, 0), '#');
+        to.put(Point::new(2, 2), '#');
+        assert_eq!(changed_area(&from, &to, (4, 3)), Some((1, 0, 2, 3)));
+
+        // Cells outside of a smaller frame count as blank
+        from = Frame::new(2, 2);
+        assert_eq!(changed_area(&from, &Frame::new(4, 3), (4, 3)), None);
+    }
+
+    #[test]
+    fn cells_become_squares_of_pixels() {
+        let mut frame = Frame::new(2, 1);
+        frame.put(Point::new(1, 0), '#');
+
+        let pixels = pixels(&frame, (0, 0, 2, 1), 2, |cell| cell.ch);
+
+        assert_eq!(pixels, [' ', ' ', '#', '#', ' ', ' ', '#', '#']);
+    }
+
+    #[test]
+    fn gifs_can_be_decoded() {
+        let mut recording = Recording::new();
+        let mut frame = Frame::new(3, 2);
+
+        recording.push(&frame, Duration::from_millis(100));
+        frame.put(Point::new(2, 1), 'o');
+        recording.push(&frame, Duration::from_millis(5));
+        frame.put(Point::new(0, 0), 'o');
+        recording.push(&frame, Duration::from_millis(5));
+        recording.push(&frame, Duration::from_secs(1));
+
+        let mut out = Vec::new();
+        write_gif(&recording, &mut out, &ImageOptions::default()).unwrap();
+
+        let mut decoder = gif::DecodeOptions::new().read_info(out.as_slice()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (12, 8));
+
+        let mut frames = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            frames.push((frame.left, frame.top, frame.width, frame.height, frame.delay));
+        }
+
+        // The two 5ms frames are merged into the last one
+        assert_eq!(frames, [(0, 0, 12, 8, 10), (0, 0, 12, 8, 101)]);
+    }
+
+    #[test]
+    fn png_names_are_numbered() {
+        assert_eq!(numbered(Path::new("frames/sand.png"), 12), Path::new("frames/sand-00012.png"));
+    }
+}
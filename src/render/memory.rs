@@ -0,0 +1,30 @@
+use super::{Frame, Renderer};
+
+/// Keeps every frame that was drawn, to look at them without a terminal
+#[derive(Debug, Default)]
+pub struct Memory {
+    pub frames: Vec<Frame>,
+    /// Whether the visualization got to its end
+    pub finished: bool,
+}
+
+impl Memory {
+    pub fn new() -> Self {
+        Memory::default()
+    }
+
+    pub fn last(&self) -> Option<&Frame> {
+        self.frames.last()
+    }
+}
+
+impl Renderer for Memory {
+    fn draw(&mut self, frame: &Frame) {
+        self.frames.push(frame.clone());
+    }
+
+    fn finish(&mut self, frame: &Frame) {
+        self.draw(frame);
+        self.finished = true;
+    }
+}
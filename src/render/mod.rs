@@ -0,0 +1,279 @@
+//! Visualizations of the simulations: the days draw each step into a [`Frame`]
+//! and hand it over to a [`Renderer`], which decides where it ends up
+
+use std::{fmt, sync::{atomic::AtomicBool, Arc}, time::Duration};
+
+use serde::Deserialize;
+
+use crate::{geometry::Point, grid::Grid, solution::Params};
+
+mod asciicast;
+mod camera;
+mod image;
+mod memory;
+mod playback;
+mod recording;
+mod snapshot;
+mod text;
+#[cfg(feature = "visualize")]
+mod terminal;
+
+pub use camera::{Camera, Rect, Scene};
+pub use image::{ImageOptions, Palette, Rgb};
+pub use memory::Memory;
+pub use playback::{Command, Playback};
+pub use recording::{Patch, RecordFormat, Recorder, Recording, Step};
+pub use snapshot::Snapshot;
+#[cfg(test)]
+pub use snapshot::assert_snapshot;
+#[cfg(feature = "visualize")]
+pub use terminal::Terminal;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Color {
+    /// Whatever the terminal uses by default
+    #[default]
+    Default,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Style {
+    pub fg: Color,
+    pub bg: Color,
+}
+
+impl Style {
+    pub const fn fg(fg: Color) -> Self {
+        Style { fg, bg: Color::Default }
+    }
+
+    pub const fn new(fg: Color, bg: Color) -> Self {
+        Style { fg, bg }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub style: Style,
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell { ch: ' ', style: Style::default() }
+    }
+}
+
+/// One picture of a visualization, `(0, 0)` is the top left cell.
+/// Anything drawn outside of the frame is clipped
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    cells: Grid<Cell>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize) -> Self {
+        Frame { cells: Grid::new(width, height, Cell::default()) }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    /// The cells of the frame, from `(0, 0)`
+    pub fn rect(&self) -> Rect {
+        Rect::new(Point::origin(), self.width(), self.height())
+    }
+
+    pub fn get(&self, point: Point) -> Option<Cell> {
+        self.cells.get(point).copied()
+    }
+
+    pub fn put(&mut self, point: Point, ch: char) {
+        self.put_styled(point, ch, Style::default());
+    }
+
+    pub fn put_styled(&mut self, point: Point, ch: char, style: Style) {
+        if let Some(cell) = self.cells.get_mut(point) {
+            *cell = Cell { ch, style };
+        }
+    }
+
+    /// Writes the text from left to right starting at `point`, it doesn't wrap
+    pub fn text(&mut self, point: Point, text: &str) {
+        for (i, ch) in text.chars().enumerate() {
+            self.put(point + Point::new(i as i32, 0), ch);
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        self.cells.rows()
+    }
+
+    /// The part of the frame inside of `rect`, cells outside of the frame are left blank
+    pub fn crop(&self, rect: Rect) -> Frame {
+        let mut cropped = rect.frame();
+
+        for y in 0..(rect.height as i32) {
+            for x in 0..(rect.width as i32) {
+                if let Some(cell) = self.get(rect.min + Point::new(x, y)) {
+                    cropped.put_styled(Point::new(x, y), cell.ch, cell.style);
+                }
+            }
+        }
+
+        cropped
+    }
+}
+
+/// The characters of the frame without any styles, trailing spaces of the rows are left out
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            let line: String = row.iter().map(|cell| cell.ch).collect();
+            write!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Where the frames of a visualization go
+pub trait Renderer {
+    /// Shows the next step of the visualization
+    fn draw(&mut self, frame: &Frame);
+
+    /// Shows the next step of a visualization that might not fit, the whole world by default.
+    /// The frames are only drawn when they're going to be shown, which jumping to the end skips
+    fn draw_scene(&mut self, scene: &Scene) {
+        if let Some(frame) = scene.frame(scene.world) {
+            self.draw(&frame);
+        }
+    }
+
+    fn finish_scene(&mut self, scene: &Scene) {
+        if let Some(frame) = scene.frame(scene.world) {
+            self.finish(&frame);
+        }
+    }
+
+    /// Shows the final state of the visualization, a terminal keeps it up until a key is pressed
+    fn finish(&mut self, frame: &Frame) {
+        self.draw(frame);
+    }
+}
+
+/// Draws every frame with each of the renderers
+impl Renderer for Vec<Box<dyn Renderer>> {
+    fn draw(&mut self, frame: &Frame) {
+        for renderer in self {
+            renderer.draw(frame);
+        }
+    }
+
+    fn finish(&mut self, frame: &Frame) {
+        for renderer in self {
+            renderer.finish(frame);
+        }
+    }
+
+    fn draw_scene(&mut self, scene: &Scene) {
+        for renderer in self {
+            renderer.draw_scene(scene);
+        }
+    }
+
+    fn finish_scene(&mut self, scene: &Scene) {
+        for renderer in self {
+            renderer.finish_scene(scene);
+        }
+    }
+}
+
+/// The renderer of a visualization, if `--visualize` or `--record` was given.
+/// `delay` is how long each frame stays up, the days pass their own default which `--delay-ms` overrides
+pub fn renderer(params: &Params, delay: Duration) -> Option<Box<dyn Renderer>> {
+    let delay = params.delay_or(delay);
+    let mut renderers: Vec<Box<dyn Renderer>> = Vec::new();
+
+    // Once quit the terminal stays closed, for the runs of a benchmark that are left
+    if params.visualize && ! params.quit() {
+        renderers.extend(terminal(delay, params.quit.clone()));
+    }
+
+    if let Some(recording) = &params.recording {
+        renderers.push(Box::new(Recorder::new(recording.clone(), delay)));
+    }
+
+    match renderers.len() {
+        0 => None,
+        1 => renderers.pop(),
+        _ => Some(Box::new(renderers)),
+    }
+}
+
+#[cfg(feature = "visualize")]
+fn terminal(delay: Duration, quit: Arc<AtomicBool>) -> Option<Box<dyn Renderer>> {
+    Some(Box::new(Terminal::new(delay, quit)))
+}
+
+// The command line refuses --visualize when there's no terminal to draw in
+#[cfg(not(feature = "visualize"))]
+fn terminal(_: Duration, _: Arc<AtomicBool>) -> Option<Box<dyn Renderer>> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drawing_outside_of_the_frame_is_clipped() {
+        let mut frame = Frame::new(3, 2);
+
+        frame.put(Point::new(-1, 0), '#');
+        frame.put(Point::new(3, 1), '#');
+        frame.text(Point::new(1, 1), "abc");
+
+        assert_eq!(frame.to_string(), "\n ab");
+    }
+
+    #[test]
+    fn styles_are_kept_next_to_the_characters() {
+        let mut frame = Frame::new(2, 1);
+        let red = Style::fg(Color::Red);
+
+        frame.put_styled(Point::new(1, 0), 'o', red);
+
+        assert_eq!(frame.get(Point::new(0, 0)), Some(Cell::default()));
+        assert_eq!(frame.get(Point::new(1, 0)), Some(Cell { ch: 'o', style: red }));
+        assert_eq!(frame.get(Point::new(2, 0)), None);
+    }
+
+    #[test]
+    fn cropping_leaves_the_cells_outside_of_the_frame_blank() {
+        let mut frame = Frame::new(3, 2);
+        frame.text(Point::new(0, 0), "abc");
+        frame.text(Point::new(0, 1), "def");
+
+        assert_eq!(frame.crop(Rect::new(Point::new(1, 0), 2, 2)).to_string(), "bc\nef");
+        assert_eq!(frame.crop(Rect::new(Point::new(-1, 1), 3, 2)).to_string(), " de\n");
+    }
+}
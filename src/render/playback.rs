@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use crate::geometry::Point;
+
+/// The keys of the terminal visualizations, shown on their status line
+pub const KEYS: &str = "space: pause  s: step  +/-: speed  arrows: pan  f: follow  e: end  q: quit";
+
+/// The slowest that `-` goes, a frame every 10 seconds
+const MAX_DELAY: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    /// Pauses or resumes the visualization
+    Pause,
+    /// Shows the next frame and pauses
+    Step,
+    /// Halves the time between the frames
+    Faster,
+    /// Doubles the time between the frames
+    Slower,
+    /// Skips the frames until the final one
+    End,
+    /// Stops the visualization and the run
+    Quit,
+    /// Moves the camera by this many cells, it stops following the action
+    Pan(Point),
+    /// Makes the camera follow the action again
+    Follow,
+}
+
+impl Command {
+    pub fn from_key(key: char) -> Option<Self> {
+        match key {
+            ' ' | 'p' => Some(Command::Pause),
+            's' | 'n' => Some(Command::Step),
+            '+' | '=' => Some(Command::Faster),
+            '-' | '_' => Some(Command::Slower),
+            'e' => Some(Command::End),
+            'q' => Some(Command::Quit),
+            'f' => Some(Command::Follow),
+            _ => None,
+        }
+    }
+}
+
+/// What the keys of a visualization did so far
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Playback {
+    /// How long each frame stays up
+    pub delay: Duration,
+    pub paused: bool,
+    /// Whether the frames are skipped until the final one
+    pub skipping: bool,
+    pub quit: bool,
+    /// How many frames were drawn so far, including the skipped ones
+    pub steps: u64,
+}
+
+impl Playback {
+    pub fn new(delay: Duration) -> Self {
+        Playback { delay, paused: false, skipping: false, quit: false, steps: 0 }
+    }
+
+    /// Returns whether the current frame is done waiting
+    pub fn apply(&mut self, command: Command) -> bool {
+        match command {
+            Command::Pause => {
+                self.paused = ! self.paused;
+                false
+            },
+            Command::Step => {
+                self.paused = true;
+                true
+            },
+            Command::Faster => {
+                // Below a millisecond it's as fast as the terminal can keep up with
+                self.delay = Some(self.delay / 2).filter(|d| *d >= Duration::from_millis(1)).unwrap_or(Duration::ZERO);
+                false
+            },
+            Command::Slower => {
+                self.delay = (self.delay * 2).clamp(Duration::from_millis(1), MAX_DELAY);
+                false
+            },
+            Command::End => {
+                self.skipping = true;
+                self.paused = false;
+                true
+            },
+            Command::Quit => {
+                self.quit = true;
+                true
+            },
+            // The camera of the terminal takes care of these
+            Command::Pan(_) | Command::Follow => false,
+        }
+    }
+
+    pub fn status(&self) -> String {
+        let state = if self.paused { "paused" } else { "playing" };
+
+        format!("Step {}  {:?} per step  {}  |  {}", self.steps, self.delay, state, KEYS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stepping_pauses() {
+        let mut playback = Playback::new(Duration::from_millis(50));
+
+        assert!(! playback.apply(Command::Pause));
+        assert!(playback.paused);
+        assert!(! playback.apply(Command::Pause));
+        assert!(! playback.paused);
+
+        assert!(playback.apply(Command::Step));
+        assert!(playback.paused);
+    }
+
+    #[test]
+    fn the_speed_is_doubled_or_halved() {
+        let mut playback = Playback::new(Duration::from_millis(4));
+
+        playback.apply(Command::Slower);
+        assert_eq!(playback.delay, Duration::from_millis(8));
+
+        for _ in 0..4 {
+            playback.apply(Command::Faster);
+        }
+        assert_eq!(playback.delay, Duration::ZERO);
+
+        // Days that don't wait between the frames can be slowed down too
+        playback.apply(Command::Slower);
+        assert_eq!(playback.delay, Duration::from_millis(1));
+
+        for _ in 0..20 {
+            playback.apply(Command::Slower);
+        }
+        assert_eq!(playback.delay, MAX_DELAY);
+    }
+
+    #[test]
+    fn jumping_to_the_end_resumes() {
+        let mut playback = Playback::new(Duration::from_millis(50));
+
+        playback.apply(Command::Pause);
+        assert!(playback.apply(Command::End));
+        assert!(playback.skipping);
+        assert!(! playback.paused);
+    }
+
+    #[test]
+    fn the_status_line_has_the_step_and_speed() {
+        let mut playback = Playback::new(Duration::from_millis(100));
+        playback.steps = 42;
+        playback.apply(Command::Pause);
+
+        assert_eq!(playback.status(), format!("Step 42  100ms per step  paused  |  {}", KEYS));
+        assert_eq!(Command::from_key(' '), Some(Command::Pause));
+        assert_eq!(Command::from_key('x'), None);
+    }
+}
@@ -0,0 +1,339 @@
+use std::{ffi::OsStr, fs::{self, File}, io::BufWriter, mem, path::Path, sync::{Arc, Mutex, MutexGuard}, time::Duration};
+
+use crate::geometry::Point;
+
+use super::{asciicast, image::{self, ImageOptions}, text, Camera, Cell, Frame, Renderer, Scene};
+
+// Players can't show frames that are closer together than a few milliseconds anyway, so the ones in between
+// are left out. The days that don't wait between frames get this much for each of them
+const MIN_FRAME: Duration = Duration::from_millis(10);
+
+/// The most frames that a recording keeps before it skips to the final frame of a part, about 5 minutes
+/// of playback. The sand of day 14 takes millions of them with the real input
+pub const MAX_FRAMES: usize = 30_000;
+
+/// How long the final state of a visualization stays up at the end of the recording
+const FINISH_HOLD: Duration = Duration::from_secs(2);
+
+/// The most cells (width, height) that a recording shows, larger worlds are followed by a camera.
+/// Drawing the whole cave of day 14 for every step of the sand would take ages
+const MAX_SIZE: (usize, usize) = (200, 100);
+
+/// What a frame changed compared to the one before it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Patch {
+    /// The first frame, or one of a different size than the frame before it
+    Full(Frame),
+    /// The cells that changed, row by row
+    Cells(Vec<(Point, Cell)>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub patch: Patch,
+    /// How long the frame stays up
+    pub duration: Duration,
+}
+
+/// The frames of a visualization along with how long each of them stays up.
+/// Only the changes between the frames are kept, the tower of day 17 takes tens of thousands of them
+#[derive(Debug, Default)]
+pub struct Recording {
+    steps: Vec<Step>,
+    previous: Option<Frame>,
+    /// The frames that were left out after `MAX_FRAMES`
+    dropped: usize,
+}
+
+/// The file formats that a recording can be saved as, picked by the extension of the file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordFormat {
+    /// `.cast`: https://docs.asciinema.org/manual/asciicast/v2/
+    Asciicast,
+    /// `.gif`: An animation with a square of pixels per cell
+    Gif,
+    /// `.png`: A numbered image per frame, `frames/sand.png` becomes `frames/sand-00001.png`, ...
+    Png,
+    /// `.txt`: The characters of every frame, one after the other
+    Text,
+}
+
+impl RecordFormat {
+    pub fn of(path: &Path) -> Result<Self, String> {
+        match path.extension().and_then(OsStr::to_str) {
+            Some("cast") => Ok(RecordFormat::Asciicast),
+            Some("gif") => Ok(RecordFormat::Gif),
+            Some("png") => Ok(RecordFormat::Png),
+            Some("txt") => Ok(RecordFormat::Text),
+            _ => Err(format!("Can't tell how to record to {}, expected a .cast, .gif, .png or .txt file", path.display())),
+        }
+    }
+}
+
+impl Recording {
+    pub fn new() -> Self {
+        Recording::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Adds a frame that stays up for `duration`
+    pub fn push(&mut self, frame: &Frame, duration: Duration) {
+        let patch = match &self.previous {
+            Some(previous) if previous.width() == frame.width() && previous.height() == frame.height() => {
+                Patch::Cells(changes(previous, frame))
+            },
+            _ => Patch::Full(frame.clone()),
+        };
+
+        self.steps.push(Step { patch, duration });
+        self.previous = Some(frame.clone());
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// How many frames were left out because the recording already had `MAX_FRAMES` of them
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    /// The size that fits every frame
+    pub fn size(&self) -> (usize, usize) {
+        self.steps
+            .iter()
+            .filter_map(|step| match &step.patch {
+                Patch::Full(frame) => Some((frame.width(), frame.height())),
+                Patch::Cells(_) => None,
+            })
+            .fold((0, 0), |(width, height), (w, h)| (width.max(w), height.max(h)))
+    }
+
+    /// Calls `f` with every frame in order, along with its step
+    pub fn replay(&self, mut f: impl FnMut(&Frame, &Step) -> Result<(), String>) -> Result<(), String> {
+        let mut frame = Frame::new(0, 0);
+
+        for step in &self.steps {
+            match &step.patch {
+                Patch::Full(full) => frame = full.clone(),
+                Patch::Cells(cells) => {
+                    for &(point, cell) in cells {
+                        frame.put_styled(point, cell.ch, cell.style);
+                    }
+                },
+            }
+
+            f(&frame, step)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn save(&self, path: &Path, title: &str, options: &ImageOptions) -> Result<(), String> {
+        let failed = |err: &dyn std::fmt::Display| format!("Failed to write {}: {}", path.display(), err);
+
+        if let Some(parent) = path.parent().filter(|p| ! p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|err| failed(&err))?;
+        }
+
+        let create = || File::create(path).map(BufWriter::new).map_err(|err| failed(&err));
+
+        match RecordFormat::of(path)? {
+            RecordFormat::Asciicast => asciicast::write(self, &mut create()?, title).map_err(|err| failed(&err)),
+            RecordFormat::Gif => image::write_gif(self, create()?, options).map_err(|err| failed(&err)),
+            RecordFormat::Png => image::write_pngs(self, path, options),
+            RecordFormat::Text => text::write(self, &mut create()?).map_err(|err| failed(&err)),
+        }
+    }
+}
+
+/// The cells of `to` that are different in `from`, they have to be of the same size
+fn changes(from: &Frame, to: &Frame) -> Vec<(Point, Cell)> {
+    from.rows()
+        .zip(to.rows())
+        .enumerate()
+        .flat_map(|(y, (old, new))| {
+            old.iter()
+                .zip(new)
+                .enumerate()
+                .filter(|(_, (old, new))| old != new)
+                .map(move |(x, (_, &new))| (Point::new(x as i32, y as i32), new))
+        })
+        .collect()
+}
+
+/// Adds the frames to a recording, the frames of both parts of a day end up in the same one
+pub struct Recorder {
+    recording: Arc<Mutex<Recording>>,
+    delay: Duration,
+    camera: Camera,
+    /// How long the frames that were left out since the last one would have stayed up
+    skipped: Duration,
+}
+
+impl Recorder {
+    pub fn new(recording: Arc<Mutex<Recording>>, delay: Duration) -> Self {
+        Recorder { recording, delay, camera: Camera::new(), skipped: Duration::ZERO }
+    }
+
+    fn frame(&mut self, scene: &Scene) -> Option<Frame> {
+        scene.frame(self.camera.view(scene.world, MAX_SIZE, scene.focus))
+    }
+
+    fn recording(&self) -> MutexGuard<'_, Recording> {
+        // A panic of another part while it held the lock doesn't make the frames before it any less valid
+        self.recording.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Whether the next frame is kept, the ones that aren't don't even have to be drawn
+    fn keep_next(&mut self) -> bool {
+        self.skipped += if self.delay.is_zero() { MIN_FRAME } else { self.delay };
+
+        if self.skipped < MIN_FRAME {
+            return false;
+        }
+
+        let mut recording = self.recording();
+
+        if recording.steps.len() >= MAX_FRAMES {
+            recording.dropped += 1;
+            return false;
+        }
+
+        true
+    }
+
+    /// Adds a frame that stays up for the frames that were left out before it too
+    fn push(&mut self, frame: &Frame) {
+        let duration = mem::take(&mut self.skipped);
+
+        self.recording().push(frame, duration);
+    }
+}
+
+impl Renderer for Recorder {
+    fn draw(&mut self, frame: &Frame) {
+        if self.keep_next() {
+            self.push(frame);
+        }
+    }
+
+    fn finish(&mut self, frame: &Frame) {
+        self.skipped = Duration::ZERO;
+        self.recording().push(frame, FINISH_HOLD);
+    }
+
+    fn draw_scene(&mut self, scene: &Scene) {
+        if ! self.keep_next() {
+            return;
+        }
+
+        if let Some(frame) = self.frame(scene) {
+            self.push(&frame);
+        }
+    }
+
+    fn finish_scene(&mut self, scene: &Scene) {
+        if let Some(frame) = self.frame(scene) {
+            self.finish(&frame);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(text: &str) -> Frame {
+        let mut frame = Frame::new(3, 2);
+
+        for (y, line) in text.lines().enumerate() {
+            frame.text(Point::new(0, y as i32), line);
+        }
+
+        frame
+    }
+
+    #[test]
+    fn only_the_changes_between_frames_are_kept() {
+        let mut recording = Recording::new();
+        let step = Duration::from_millis(100);
+
+        recording.push(&frame("ab\ncd"), step);
+        recording.push(&frame("ab\ncy"), step);
+        recording.push(&Frame::new(1, 1), step);
+
+        let patches: Vec<&Patch> = recording.steps().iter().map(|step| &step.patch).collect();
+        let y = Cell { ch: 'y', ..Cell::default() };
+
+        assert_eq!(patches, [
+            &Patch::Full(frame("ab\ncd")),
+            &Patch::Cells(vec![(Point::new(1, 1), y)]),
+            &Patch::Full(Frame::new(1, 1)),
+        ]);
+        assert_eq!(recording.size(), (3, 2));
+    }
+
+    #[test]
+    fn replaying_applies_the_changes() {
+        let mut recording = Recording::new();
+
+        for text in ["ab", "xb", "xy"] {
+            recording.push(&frame(text), Duration::from_millis(100));
+        }
+
+        let mut frames = Vec::new();
+        recording.replay(|frame, _| {
+            frames.push(frame.to_string());
+            Ok(())
+        }).unwrap();
+
+        assert_eq!(frames, ["ab\n", "xb\n", "xy\n"]);
+    }
+
+    #[test]
+    fn frames_closer_together_than_the_minimum_are_left_out() {
+        let recording = Arc::new(Mutex::new(Recording::new()));
+        let mut recorder = Recorder::new(recording.clone(), Duration::from_millis(4));
+
+        for text in ["a", "b", "c", "d", "e", "f", "g"] {
+            recorder.draw(&frame(text));
+        }
+        recorder.finish(&frame("h"));
+
+        let recording = recording.lock().unwrap();
+        let durations: Vec<u64> = recording.steps().iter().map(|step| step.duration.as_millis() as u64).collect();
+
+        assert_eq!(durations, [12, 12, 2000]);
+        assert_eq!(recording.previous, Some(frame("h")));
+    }
+
+    #[test]
+    fn long_recordings_skip_to_the_end() {
+        let recording = Arc::new(Mutex::new(Recording::new()));
+        let mut recorder = Recorder::new(recording.clone(), Duration::ZERO);
+
+        for _ in 0..(MAX_FRAMES + 10) {
+            recorder.draw(&Frame::new(1, 1));
+        }
+        recorder.finish(&frame("end"));
+
+        let recording = recording.lock().unwrap();
+
+        assert_eq!((recording.steps().len(), recording.dropped()), (MAX_FRAMES + 1, 10));
+        assert_eq!(recording.previous, Some(frame("end")));
+    }
+
+    #[test]
+    fn the_format_comes_from_the_extension() {
+        assert_eq!(RecordFormat::of(Path::new("sand.cast")), Ok(RecordFormat::Asciicast));
+        assert_eq!(RecordFormat::of(Path::new("out/sand.gif")), Ok(RecordFormat::Gif));
+        assert_eq!(RecordFormat::of(Path::new("frames/sand.png")), Ok(RecordFormat::Png));
+        assert_eq!(RecordFormat::of(Path::new("sand.txt")), Ok(RecordFormat::Text));
+        assert!(RecordFormat::of(Path::new("sand.mp4")).is_err());
+        assert!(RecordFormat::of(Path::new("sand")).is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use super::{Camera, Frame, Renderer, Scene};
+
+/// How much of the world a snapshot shows, about what a terminal fits
+const SIZE: (usize, usize) = (80, 40);
+
+/// Keeps the final frame of a visualization as plain text, the steps before it aren't even drawn
+#[derive(Debug, Default)]
+pub struct Snapshot {
+    pub steps: usize,
+    pub frame: Option<Frame>,
+}
+
+impl Snapshot {
+    pub fn new() -> Self {
+        Snapshot::default()
+    }
+
+    /// The text of the final frame, empty when the visualization didn't finish
+    pub fn text(&self) -> String {
+        self.frame.as_ref().map(Frame::to_string).unwrap_or_default()
+    }
+}
+
+impl Renderer for Snapshot {
+    fn draw(&mut self, _: &Frame) {
+        self.steps += 1;
+    }
+
+    fn finish(&mut self, frame: &Frame) {
+        self.frame = Some(frame.clone());
+    }
+
+    fn draw_scene(&mut self, _: &Scene) {
+        self.steps += 1;
+    }
+
+    fn finish_scene(&mut self, scene: &Scene) {
+        let view = Camera::new().view(scene.world, SIZE, scene.focus);
+
+        self.frame = scene.frame(view);
+    }
+}
+
+/// Compares the final frame with a file of the repository, `UPDATE_SNAPSHOTS=1 cargo test` writes the files
+/// again after a change to how a day draws
+#[cfg(test)]
+pub fn assert_snapshot(path: &str, snapshot: &Snapshot) {
+    let actual = snapshot.text();
+    let full_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(path);
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&full_path, format!("{}\n", actual)).unwrap();
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&full_path)
+        .unwrap_or_else(|err| panic!("Failed to read {}: {}, UPDATE_SNAPSHOTS=1 writes it", path, err));
+    let expected = expected.strip_suffix('\n').unwrap_or(&expected);
+
+    if expected != actual {
+        panic!("The final frame doesn't match {} (- snapshot, + frame):\n{}", path, diff(expected, &actual));
+    }
+}
+
+/// The lines of both, with the ones that differ marked
+#[cfg(test)]
+fn diff(expected: &str, actual: &str) -> String {
+    let (expected, actual): (Vec<&str>, Vec<&str>) = (expected.lines().collect(), actual.lines().collect());
+    let mut lines = Vec::new();
+
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => lines.push(format!("  {}", e)),
+            (e, a) => {
+                lines.extend(e.map(|e| format!("- {}", e)));
+                lines.extend(a.map(|a| format!("+ {}", a)));
+            },
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{geometry::Point, render::Rect};
+
+    use super::*;
+
+    #[test]
+    fn only_the_final_frame_is_drawn() {
+        let mut snapshot = Snapshot::new();
+        let world = Rect::new(Point::origin(), 200, 2);
+        let draw = |view: Rect| {
+            assert_eq!(view, Rect::new(Point::new(80, 0), 80, 2), "only the final frame is drawn");
+
+            let mut frame = view.frame();
+            frame.text(view.to_frame(Point::new(120, 1)), "o");
+            Some(frame)
+        };
+
+        snapshot.draw_scene(&Scene { world, focus: Point::new(0, 0), draw: &|_| unreachable!() });
+        snapshot.finish_scene(&Scene { world, focus: Point::new(120, 1), draw: &draw });
+
+        assert_eq!(snapshot.steps, 1);
+        assert_eq!(snapshot.text(), format!("\n{}o", " ".repeat(40)));
+    }
+
+    #[test]
+    fn the_differing_lines_are_marked() {
+        assert_eq!(diff("a\nb\nc", "a\nx\nc\nd"), "  a\n- b\n+ x\n  c\n+ d");
+    }
+}
@@ -0,0 +1,228 @@
+use std::{collections::HashMap, sync::{atomic::{AtomicBool, Ordering}, Arc}, time::{Duration, Instant}};
+
+use ncurses::*;
+
+use crate::geometry::Point;
+
+use super::{Camera, Color, Command, Frame, Playback, Renderer, Scene, Style};
+
+/// Draws the frames with ncurses, the terminal is taken over until it's dropped.
+/// Worlds that don't fit in the terminal are followed by a camera, and the last line shows
+/// the status of the playback, which the keys of [`Command`] control.
+/// Quitting gives the terminal back right away and tells the caller through `quit`
+pub struct Terminal {
+    playback: Playback,
+    camera: Camera,
+    /// The ncurses color pair of each style that was drawn so far
+    pairs: HashMap<Style, i16>,
+    quit: Arc<AtomicBool>,
+}
+
+impl Terminal {
+    pub fn new(delay: Duration, quit: Arc<AtomicBool>) -> Self {
+        initscr();
+        noecho();
+        cbreak();
+        keypad(stdscr(), true);
+        curs_set(CURSOR_VISIBILITY::CURSOR_INVISIBLE);
+
+        if has_colors() {
+            start_color();
+            use_default_colors();
+        }
+
+        Terminal { playback: Playback::new(delay), camera: Camera::new(), pairs: HashMap::new(), quit }
+    }
+
+    fn pair(&mut self, style: Style) -> i16 {
+        if style == Style::default() || ! has_colors() {
+            return 0;
+        }
+
+        let next = self.pairs.len() as i16 + 1;
+
+        *self.pairs.entry(style).or_insert_with(|| {
+            init_pair(next, color(style.fg), color(style.bg));
+            next
+        })
+    }
+
+    /// The cells that the frames can use, the size is asked for every time since the terminal can be resized
+    fn size() -> (usize, usize) {
+        (COLS().max(1) as usize, (LINES() - 1).max(1) as usize)
+    }
+
+    /// Draws the part of the scene that the camera is pointed at, returns false when there was nothing to show
+    fn show(&mut self, scene: &Scene) -> bool {
+        let view = self.camera.view(scene.world, Terminal::size(), scene.focus);
+
+        let Some(frame) = scene.frame(view) else {
+            return false;
+        };
+
+        erase();
+
+        // The view fits, unless the terminal was made smaller since
+        let (cols, lines) = Terminal::size();
+
+        for (y, row) in frame.rows().enumerate().take(lines) {
+            mv(y as i32, 0);
+
+            for cell in row.iter().take(cols) {
+                let pair = self.pair(cell.style);
+
+                attron(COLOR_PAIR(pair));
+                addch(cell.ch as chtype);
+                attroff(COLOR_PAIR(pair));
+            }
+        }
+
+        self.status(&self.playback.status());
+        true
+    }
+
+    fn status(&self, status: &str) {
+        mv(LINES() - 1, 0);
+        clrtoeol();
+        addnstr(status, COLS());
+        refresh();
+    }
+
+    /// Waits for the delay of the frame while handling the keys, or until a step when paused
+    fn wait(&mut self, scene: &Scene) {
+        let start = Instant::now();
+
+        loop {
+            let wait = match self.playback.paused {
+                true => -1,
+                false => self.playback.delay.saturating_sub(start.elapsed()).as_millis().min(i32::MAX as u128) as i32,
+            };
+
+            timeout(wait);
+            let key = getch();
+
+            // Timed out
+            if key == ERR {
+                return;
+            }
+
+            let Some(command) = command(key) else {
+                continue;
+            };
+
+            if self.control(command, scene) {
+                return;
+            }
+
+            self.status(&self.playback.status());
+        }
+    }
+
+    /// Carries out the command of a key, returns whether the current frame is done waiting
+    fn control(&mut self, command: Command, scene: &Scene) -> bool {
+        match command {
+            Command::Pan(delta) => self.camera.pan(delta),
+            Command::Follow => self.camera.follow(),
+            command => {
+                let done = self.playback.apply(command);
+
+                if self.playback.quit {
+                    self.quit();
+                }
+
+                return done;
+            },
+        }
+
+        self.show(scene);
+        false
+    }
+
+    /// Gives the terminal back, the frames that are drawn after this are ignored
+    fn quit(&mut self) {
+        self.playback.quit = true;
+        endwin();
+        eprintln!("Quit the visualization after {} steps", self.playback.steps);
+        self.quit.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Renderer for Terminal {
+    fn draw(&mut self, frame: &Frame) {
+        self.draw_scene(&Scene { world: frame.rect(), focus: Point::origin(), draw: &|view| Some(frame.crop(view)) });
+    }
+
+    fn finish(&mut self, frame: &Frame) {
+        self.finish_scene(&Scene { world: frame.rect(), focus: Point::origin(), draw: &|view| Some(frame.crop(view)) });
+    }
+
+    fn draw_scene(&mut self, scene: &Scene) {
+        if self.playback.quit {
+            return;
+        }
+
+        self.playback.steps += 1;
+
+        if ! self.playback.skipping && self.show(scene) {
+            self.wait(scene);
+        }
+    }
+
+    fn finish_scene(&mut self, scene: &Scene) {
+        if self.playback.quit {
+            return;
+        }
+
+        self.show(scene);
+
+        let status = format!("Finished after {} steps, press any key to continue (q: quit)", self.playback.steps);
+        self.status(&status);
+
+        timeout(-1);
+
+        // The camera can still be moved around, any other key continues
+        loop {
+            match command(getch()) {
+                Some(command @ (Command::Pan(_) | Command::Follow)) => {
+                    self.control(command, scene);
+                    self.status(&status);
+                },
+                Some(Command::Quit) => return self.quit(),
+                _ => return,
+            }
+        }
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        if ! self.playback.quit {
+            endwin();
+        }
+    }
+}
+
+/// What a key read by `getch` does, the arrows move the camera by a few cells
+fn command(key: i32) -> Option<Command> {
+    match key {
+        KEY_UP => Some(Command::Pan(Point::new(0, -2))),
+        KEY_DOWN => Some(Command::Pan(Point::new(0, 2))),
+        KEY_LEFT => Some(Command::Pan(Point::new(-4, 0))),
+        KEY_RIGHT => Some(Command::Pan(Point::new(4, 0))),
+        key => char::from_u32(key as u32).and_then(Command::from_key),
+    }
+}
+
+fn color(color: Color) -> i16 {
+    match color {
+        Color::Default => -1,
+        Color::Black => COLOR_BLACK,
+        Color::Red => COLOR_RED,
+        Color::Green => COLOR_GREEN,
+        Color::Yellow => COLOR_YELLOW,
+        Color::Blue => COLOR_BLUE,
+        Color::Magenta => COLOR_MAGENTA,
+        Color::Cyan => COLOR_CYAN,
+        Color::White => COLOR_WHITE,
+    }
+}
@@ -0,0 +1,40 @@
+//! Recordings as plain text, to read the frames or diff them without a terminal
+
+use std::io::Write;
+
+use super::Recording;
+
+/// Every frame without its styles, each below a line with its number and how long it stays up
+pub fn write(recording: &Recording, out: &mut impl Write) -> Result<(), String> {
+    let mut number = 0;
+
+    recording.replay(|frame, step| {
+        number += 1;
+
+        writeln!(out, "--- {} ({:?})\n{}", number, step.duration, frame).map_err(|err| err.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{geometry::Point, render::Frame};
+
+    use super::*;
+
+    #[test]
+    fn frames_are_numbered() {
+        let mut recording = Recording::new();
+        let mut frame = Frame::new(3, 2);
+
+        recording.push(&frame, Duration::from_millis(100));
+        frame.text(Point::new(1, 1), "ab");
+        recording.push(&frame, Duration::from_secs(2));
+
+        let mut out = Vec::new();
+        write(&recording, &mut out).unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "--- 1 (100ms)\n\n\n--- 2 (2s)\n\n ab\n");
+    }
+}
@@ -0,0 +1,256 @@
+use std::{fs::{self, OpenOptions}, io::{ErrorKind, Write}, path::{Path, PathBuf}};
+
+use crate::days;
+
+/// Where the days are registered, relative to the root of the repository
+pub const REGISTRY_PATH: &str = "src/days.rs";
+
+/// Where the generators of the days are registered, relative to the root of the repository
+pub const GENERATORS_PATH: &str = "src/generate.rs";
+
+/// The `mod.rs` of a new day, `DAY_NUMBER` is replaced with the number of the day
+const TEMPLATE: &str = r#"use crate::{error::ParseError, generate::Rng, solution::{Params, Solution}};
+
+pub struct Day;
+
+impl Solution for Day {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input, _: &Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input, _: &Params) -> String {
+        part2(input)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    Ok(input.lines().map(String::from).collect())
+}
+
+fn part1(lines: &[String]) -> String {
+    format!("Day DAY_NUMBER part 1 isn't solved yet ({} lines)", lines.len())
+}
+
+fn part2(lines: &[String]) -> String {
+    format!("Day DAY_NUMBER part 2 isn't solved yet ({} lines)", lines.len())
+}
+
+/// `size` lines of random numbers, until the format of the input is known
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size).map(|_| format!("{}\n", rng.range(0..=9))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("exampleinput");
+
+    #[test]
+    #[ignore = "the answer of the example isn't filled in yet"]
+    fn example_part1() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part1(&input), "");
+    }
+
+    #[test]
+    #[ignore = "the answer of the example isn't filled in yet"]
+    fn example_part2() {
+        let input = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2(&input), "");
+    }
+}
+"#;
+
+/// Creates the folder of a day with a skeleton solution and an empty example, and registers it in `src/days.rs`
+/// and its generator in `src/generate.rs`. Files that are already there are left alone, returns the ones that were
+/// created or changed
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    let dir = root.join(days::dir_name(day));
+    let mut changed = Vec::new();
+
+    fs::create_dir_all(&dir).map_err(|err| format!("Failed to create {}: {}", dir.display(), err))?;
+
+    for (file_name, contents) in [("mod.rs", TEMPLATE.replace("DAY_NUMBER", &day.to_string())), ("exampleinput", String::new())] {
+        let path = dir.join(file_name);
+
+        if create(&path, &contents)? {
+            changed.push(path);
+        }
+    }
+
+    for (path, register) in [(REGISTRY_PATH, register as fn(&str, u8) -> _), (GENERATORS_PATH, register_generator)] {
+        let path = root.join(path);
+        let contents = fs::read_to_string(&path)
+            .map_err(|err| format!("Failed to read {}: {}", path.display(), err))?;
+
+        if let Some(contents) = register(&contents, day)? {
+            fs::write(&path, contents).map_err(|err| format!("Failed to write {}: {}", path.display(), err))?;
+            changed.push(path);
+        }
+    }
+
+    Ok(changed)
+}
+
+/// Writes a file unless it already exists, returns whether it did
+fn create(path: &Path, contents: &str) -> Result<bool, String> {
+    let file = OpenOptions::new().write(true).create_new(true).open(path);
+
+    match file {
+        Ok(mut file) => file.write_all(contents.as_bytes()).map(|_| true),
+        Err(err) if err.kind() == ErrorKind::AlreadyExists => Ok(false),
+        Err(err) => Err(err),
+    }
+    .map_err(|err| format!("Failed to create {}: {}", path.display(), err))
+}
+
+/// Adds the module of the day and its entry in `DAYS` to the contents of `src/days.rs`, both in order of the days.
+/// `None` when both are already there
+fn register(registry: &str, day: u8) -> Result<Option<String>, String> {
+    let name = days::dir_name(day);
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+    let mut changed = false;
+
+    // The number of the day of a line, if it's one of the kind that's being added
+    let module_day = |line: &str| line.strip_prefix("pub mod day")?.strip_suffix(';')?.parse::<u8>().ok();
+    let entry_day = |line: &str| line.trim().strip_prefix('(')?.split_once(',')?.0.parse::<u8>().ok();
+
+    if ! lines.iter().any(|line| module_day(line) == Some(day)) {
+        let Some(last) = lines.iter().rposition(|line| module_day(line).is_some()) else {
+            return Err(format!("Failed to find the modules of the days in {}", REGISTRY_PATH));
+        };
+
+        // Before the `#[path]` of the first day after the new one, or after the last day
+        let index = lines.iter()
+            .position(|line| module_day(line).is_some_and(|d| d > day))
+            .map_or(last + 1, |index| index - 1);
+
+        lines.splice(index..index, [format!("#[path = \"../{}/mod.rs\"]", name), format!("pub mod {};", name)]);
+        changed = true;
+    }
+
+    let entry = format!("    ({}, &{}::Day),", day, name);
+    changed |= insert_entry(&mut lines, entry_day, day, entry)
+        .ok_or_else(|| format!("Failed to find the list of the days in {}", REGISTRY_PATH))?;
+
+    Ok(changed.then(|| lines.join("\n") + "\n"))
+}
+
+/// Adds the generator of the day to `GENERATORS` in the contents of `src/generate.rs`, in order of the days.
+/// `None` when it's already there
+fn register_generator(generators: &str, day: u8) -> Result<Option<String>, String> {
+    let mut lines: Vec<String> = generators.lines().map(String::from).collect();
+
+    let generator_day = |line: &str| line.trim().strip_prefix("Generator { day: ")?.split_once(',')?.0.parse::<u8>().ok();
+    let entry = format!(
+        "    Generator {{ day: {}, size: \"lines\", default_size: 100, generate: {}::generate }},",
+        day, days::dir_name(day),
+    );
+
+    let changed = insert_entry(&mut lines, generator_day, day, entry)
+        .ok_or_else(|| format!("Failed to find the list of the generators in {}", GENERATORS_PATH))?;
+
+    Ok(changed.then(|| lines.join("\n") + "\n"))
+}
+
+/// Inserts the entry of a day into a list of entries that `entry_day` recognizes, in order of the days.
+/// Whether it was inserted, `None` when there's no such list
+fn insert_entry(lines: &mut Vec<String>, entry_day: impl Fn(&str) -> Option<u8>, day: u8, entry: String) -> Option<bool> {
+    if lines.iter().any(|line| entry_day(line) == Some(day)) {
+        return Some(false);
+    }
+
+    let last = lines.iter().rposition(|line| entry_day(line).is_some())?;
+    let index = lines.iter()
+        .position(|line| entry_day(line).is_some_and(|d| d > day))
+        .unwrap_or(last + 1);
+
+    lines.insert(index, entry);
+    Some(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "#[path = \"../day01/mod.rs\"]
+pub mod day01;
+#[path = \"../day03/mod.rs\"]
+pub mod day03;
+
+pub static DAYS: &[(u8, &dyn DynSolution)] = &[
+    (1, &day01::Day),
+    (3, &day03::Day),
+];
+";
+
+    const GENERATORS: &str = "pub static GENERATORS: &[Generator] = &[
+    Generator { day: 1, size: \"elves\", default_size: 250, generate: day01::generate },
+    Generator { day: 3, size: \"rucksacks\", default_size: 300, generate: day03::generate },
+];
+";
+
+    #[test]
+    fn days_are_registered_in_order() {
+        let registry = register(REGISTRY, 2).unwrap().unwrap();
+
+        assert_eq!(registry, "#[path = \"../day01/mod.rs\"]
+pub mod day01;
+#[path = \"../day02/mod.rs\"]
+pub mod day02;
+#[path = \"../day03/mod.rs\"]
+pub mod day03;
+
+pub static DAYS: &[(u8, &dyn DynSolution)] = &[
+    (1, &day01::Day),
+    (2, &day02::Day),
+    (3, &day03::Day),
+];
+");
+        assert_eq!(register(&registry, 2), Ok(None));
+
+        let registry = register(REGISTRY, 20).unwrap().unwrap();
+        assert!(registry.contains("pub mod day03;\n#[path = \"../day20/mod.rs\"]\npub mod day20;\n\n"));
+        assert!(registry.contains("    (3, &day03::Day),\n    (20, &day20::Day),\n];"));
+    }
+
+    #[test]
+    fn generators_are_registered_in_order() {
+        let generators = register_generator(GENERATORS, 2).unwrap().unwrap();
+
+        assert!(generators.contains("day01::generate },\n    Generator { day: 2, size: \"lines\", default_size: 100, generate: day02::generate },\n    Generator { day: 3,"));
+        assert_eq!(register_generator(&generators, 2), Ok(None));
+
+        let generators = register_generator(GENERATORS, 20).unwrap().unwrap();
+        assert!(generators.contains("day03::generate },\n    Generator { day: 20, size: \"lines\", default_size: 100, generate: day20::generate },\n];"));
+    }
+
+    #[test]
+    fn existing_files_are_left_alone() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join(REGISTRY_PATH), REGISTRY).unwrap();
+        fs::write(root.join(GENERATORS_PATH), GENERATORS).unwrap();
+
+        let changed = new_day(&root, 2).unwrap();
+        assert_eq!(changed, ["day02/mod.rs", "day02/exampleinput", REGISTRY_PATH, GENERATORS_PATH].map(|path| root.join(path)));
+        assert!(fs::read_to_string(root.join("day02/mod.rs")).unwrap().contains("Day 2 part 1 isn't solved yet"));
+
+        fs::write(root.join("day02/mod.rs"), "solved").unwrap();
+        fs::remove_file(root.join("day02/exampleinput")).unwrap();
+
+        assert_eq!(new_day(&root, 2).unwrap(), [root.join("day02/exampleinput")]);
+        assert_eq!(fs::read_to_string(root.join("day02/mod.rs")).unwrap(), "solved");
+        assert_eq!(new_day(&root, 2).unwrap(), Vec::<PathBuf>::new());
+    }
+}
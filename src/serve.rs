@@ -0,0 +1,423 @@
+use std::{io::{self, BufRead, BufReader, Read, Write}, net::{SocketAddr, TcpListener, TcpStream}, panic::{self, AssertUnwindSafe}, sync::Arc, thread, time::{Duration, Instant}};
+
+use serde::Serialize;
+use serde_json::json;
+
+use crate::{answers::Answers, days, output::{Answer, Durations, Timings}, parallel, solution::Params};
+
+/// Inputs larger than this are turned away, the largest puzzle input is about 25KB
+const MAX_BODY: usize = 16 * 1024 * 1024;
+
+/// How long a connection has to send the whole of its request before it's given up on
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// What gets sent back for a request, the body is always json
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: serde_json::Value) -> Self {
+        Response { status, body: body.to_string() }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Response::json(status, json!({ "error": message.into() }))
+    }
+}
+
+/// The answer of a part, eg:
+///
+/// ```json
+/// {"day":1,"part":1,"answer":"24000","durations":{"parse":{"runs":1,"min_ns":4000,...},"part":{"runs":1,...}}}
+/// ```
+#[derive(Debug, Serialize)]
+struct Solved<'a> {
+    day: u8,
+    part: u8,
+    answer: Answer<'a>,
+    durations: Durations,
+}
+
+/// Answers a single request:
+///
+/// - `GET /days` lists the days and the parts of each that are solved, going by the recorded answers like `aoc verify`
+/// - `POST /day/<n>/part/<p>` solves a part of a day with the body as its input. `example=true` in the query string
+///   uses the settings of the examples, `row=<n>` and `bound=<n>` are the ones of day 15
+pub fn handle(answers: &Answers, method: &str, target: &str, body: &[u8]) -> Response {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    match (method, &segments[..]) {
+        ("GET", ["days"]) => list_days(answers),
+        ("POST", ["day", day, "part", part]) => solve(day, part, query, body),
+        (_, ["days"]) | (_, ["day", _, "part", _]) => Response::error(405, format!("{} isn't allowed on {}", method, path)),
+        _ => Response::error(404, format!("Nothing at {}, try GET /days or POST /day/<n>/part/<p>", path)),
+    }
+}
+
+fn list_days(answers: &Answers) -> Response {
+    let days: Vec<serde_json::Value> = days::DAYS
+        .iter()
+        .map(|&(day, _)| {
+            let parts: Vec<u8> = [1, 2].into_iter().filter(|&part| answers.is_solved(day, part)).collect();
+            json!({ "day": day, "parts": parts })
+        })
+        .collect();
+
+    Response::json(200, json!({ "days": days }))
+}
+
+fn solve(raw_day: &str, raw_part: &str, query: &str, body: &[u8]) -> Response {
+    let Some((day, solution)) = raw_day.parse::<u8>().ok().and_then(|day| days::get(day).map(|solution| (day, solution))) else {
+        return Response::error(404, format!("Day {} is not implemented", raw_day));
+    };
+
+    let part = match raw_part {
+        "1" => 1,
+        "2" => 2,
+        _ => return Response::error(404, format!("Day {} doesn't have a part {}", day, raw_part)),
+    };
+
+    let params = match parse_params(query) {
+        Ok(params) => params,
+        Err(err) => return Response::error(400, err),
+    };
+
+    let Ok(input) = std::str::from_utf8(body) else {
+        return Response::error(400, "The input has to be UTF-8");
+    };
+
+    let start = Instant::now();
+    let parsed = match solution.parse(input) {
+        Ok(parsed) => parsed,
+        Err(err) => return Response::json(400, json!({
+            "error": err.message,
+            "line": err.line,
+            "column": err.column,
+            "text": err.text,
+            "diagnostic": err.diagnostic("input", input),
+        })),
+    };
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => solution.part1(parsed.as_ref(), &params),
+        _ => solution.part2(parsed.as_ref(), &params),
+    };
+    let part_time = start.elapsed();
+
+    let solved = Solved {
+        day,
+        part,
+        answer: Answer::new(&answer),
+        durations: Durations {
+            parse: Timings::from_samples(&[parse_time]).expect("There's always a single run"),
+            part: Timings::from_samples(&[part_time]).expect("There's always a single run"),
+        },
+    };
+
+    Response { status: 200, body: serde_json::to_string(&solved).expect("Answers only hold strings and numbers") }
+}
+
+/// The settings of `aoc run` that can be given in the query string, eg: `example=true&row=10`
+fn parse_params(query: &str) -> Result<Params, String> {
+    let mut params = Params::default();
+
+    for pair in query.split('&').filter(|pair| ! pair.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let invalid = || format!("Invalid value for {}: {}", key, value);
+
+        match key {
+            "example" => params.example = value.parse().map_err(|_| invalid())?,
+            "row" => params.row = Some(value.parse().map_err(|_| invalid())?),
+            "bound" => params.bound = Some(value.parse().map_err(|_| invalid())?),
+            _ => return Err(format!("Unknown query parameter {}, expected example, row or bound", key)),
+        }
+    }
+
+    Ok(params)
+}
+
+/// A request that was read from a connection
+struct Request {
+    method: String,
+    target: String,
+    body: Vec<u8>,
+}
+
+/// Reads from a connection until the deadline, after which every read times out. The timeout of the socket
+/// only counts from the last byte, which would let a client that sends a byte every few seconds go on forever
+struct Deadline {
+    stream: TcpStream,
+    deadline: Instant,
+}
+
+impl Read for Deadline {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let left = self.deadline.saturating_duration_since(Instant::now());
+
+        if left.is_zero() {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+
+        self.stream.set_read_timeout(Some(left))?;
+        self.stream.read(buf)
+    }
+}
+
+/// A local http server that solves the days
+pub struct Server {
+    listener: TcpListener,
+    answers: Arc<Answers>,
+    read_timeout: Duration,
+}
+
+impl Server {
+    /// Listens on `addr`, port 0 picks a free one
+    pub fn bind(addr: &str, answers: Answers) -> io::Result<Self> {
+        Ok(Server { listener: TcpListener::bind(addr)?, answers: Arc::new(answers), read_timeout: READ_TIMEOUT })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Answers the requests until the process exits, each connection on its own thread so that a slow day
+    /// doesn't hold up the others
+    pub fn run(self) {
+        for stream in self.listener.incoming().flatten() {
+            let answers = self.answers.clone();
+            let read_timeout = self.read_timeout;
+
+            thread::spawn(move || {
+                if let Err(err) = respond(stream, &answers, read_timeout) {
+                    eprintln!("Failed to answer a request: {}", err);
+                }
+            });
+        }
+    }
+}
+
+/// Reads a single request from the connection and answers it, then closes it. A client that doesn't send
+/// the whole of its request within `read_timeout` gets a 408 instead of keeping the thread waiting
+fn respond(mut stream: TcpStream, answers: &Answers, read_timeout: Duration) -> io::Result<()> {
+    let response = match read_request(&mut stream, Instant::now() + read_timeout) {
+        Ok(Ok(request)) => {
+            // A day that panics fails its request, instead of leaving it without an answer
+            panic::catch_unwind(AssertUnwindSafe(|| handle(answers, &request.method, &request.target, &request.body))).unwrap_or_else(|payload| {
+                Response::error(500, format!("panicked: {}", parallel::panic_message(payload.as_ref())))
+            })
+        },
+        Ok(Err(response)) => response,
+        // Unix reports a timed out read as WouldBlock, Windows as TimedOut
+        Err(err) if matches!(err.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => {
+            Response::error(408, format!("The request wasn't sent within {} seconds", read_timeout.as_secs_f64()))
+        },
+        Err(err) => return Err(err),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status, reason(response.status), response.body.len(), response.body,
+    )?;
+    stream.flush()
+}
+
+/// The request, or the response for a request that doesn't make sense
+fn read_request(stream: &mut TcpStream, deadline: Instant) -> io::Result<Result<Request, Response>> {
+    let mut reader = BufReader::new(Deadline { stream: stream.try_clone()?, deadline });
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    let [method, target, _version] = request_line.split_whitespace().collect::<Vec<&str>>()[..] else {
+        return Ok(Err(Response::error(400, "Expected a request line like POST /day/1/part/1 HTTP/1.1")));
+    };
+
+    let mut length = 0;
+    let mut expects_continue = false;
+
+    loop {
+        let mut line = String::new();
+
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+
+        let Some((name, value)) = line.split_once(':') else {
+            return Ok(Err(Response::error(400, format!("Invalid header {}", line.trim_end()))));
+        };
+
+        match name.trim().to_ascii_lowercase().as_str() {
+            "content-length" => match value.trim().parse() {
+                Ok(n) => length = n,
+                Err(_) => return Ok(Err(Response::error(400, format!("Invalid Content-Length {}", value.trim())))),
+            },
+            "expect" => expects_continue = value.trim().eq_ignore_ascii_case("100-continue"),
+            _ => (),
+        }
+    }
+
+    if length > MAX_BODY {
+        return Ok(Err(Response::error(413, format!("The input can't be larger than {} bytes", MAX_BODY))));
+    }
+
+    // curl waits for this before it sends larger inputs
+    if expects_continue {
+        stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n")?;
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Ok(Request { method: String::from(method), target: String::from(target), body }))
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        413 => "Payload Too Large",
+        _ => "Internal Server Error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answers;
+
+    /// Starts a server on a free port and returns its url
+    fn start() -> String {
+        let server = Server::bind("127.0.0.1:0", answers()).unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap());
+
+        thread::spawn(move || server.run());
+
+        url
+    }
+
+    /// The status and the json body of the response
+    fn request(method: &str, url: &str, body: &str) -> (u16, serde_json::Value) {
+        let response = match ureq::request(method, url).send_string(body) {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(err) => panic!("{} {} failed: {}", method, url, err),
+        };
+
+        (response.status(), serde_json::from_str(&response.into_string().unwrap()).unwrap())
+    }
+
+    fn answers() -> Answers {
+        Answers::load(answers::DEFAULT_PATH.as_ref()).unwrap()
+    }
+
+    fn example(day: u8) -> String {
+        std::fs::read_to_string(format!("{}/exampleinput", days::dir_name(day))).unwrap()
+    }
+
+    #[test]
+    fn lists_the_days_and_their_solved_parts() {
+        let (status, body) = request("GET", &format!("{}/days", start()), "");
+        let days = body["days"].as_array().unwrap();
+
+        assert_eq!(status, 200);
+        assert_eq!(days.len(), days::DAYS.len());
+        assert_eq!(days[0], json!({ "day": 1, "parts": [1, 2] }));
+
+        let answers = answers();
+        let unsolved = days::DAYS.iter().position(|&(day, _)| ! answers.is_solved(day, 1)).expect("every day is solved");
+        assert_eq!(days[unsolved]["parts"], json!([]));
+    }
+
+    #[test]
+    fn solves_a_part_of_a_day() {
+        let url = start();
+
+        let (status, body) = request("POST", &format!("{}/day/1/part/2", url), &example(1));
+        assert_eq!((status, body["day"].clone(), body["part"].clone(), body["answer"].clone()), (200, json!(1), json!(2), json!("45000")));
+        assert_eq!(body["durations"]["part"]["runs"], json!(1));
+
+        let (status, body) = request("POST", &format!("{}/day/15/part/1?example=true", url), &example(15));
+        assert_eq!((status, body["answer"].clone()), (200, json!("26")));
+
+        let (_, body) = request("POST", &format!("{}/day/15/part/1?row=9", url), &example(15));
+        assert_eq!(body["answer"], json!("25"));
+    }
+
+    #[test]
+    fn bad_inputs_are_bad_requests() {
+        let (status, body) = request("POST", &format!("{}/day/4/part/1", start()), "1-2,3-4\n2-4,6x8\n");
+
+        assert_eq!(status, 400);
+        assert_eq!((body["error"].clone(), body["line"].clone(), body["column"].clone()), (json!("expected a section range like 2-4"), json!(2), json!(5)));
+        assert_eq!(body["text"], json!("6x8"));
+    }
+
+    #[test]
+    fn unknown_days_parts_and_paths_are_not_found() {
+        let url = start();
+
+        assert_eq!(request("POST", &format!("{}/day/25/part/1", url), "").0, 404);
+        assert_eq!(request("POST", &format!("{}/day/1/part/3", url), "").0, 404);
+        assert_eq!(request("GET", &format!("{}/answers", url), "").0, 404);
+        assert_eq!(request("GET", &format!("{}/day/1/part/1", url), "").0, 405);
+        assert_eq!(request("POST", &format!("{}/day/1/part/1?row=x", url), "").0, 400);
+    }
+
+    #[test]
+    fn requests_that_stop_halfway_time_out() {
+        let server = Server { read_timeout: Duration::from_millis(100), ..Server::bind("127.0.0.1:0", answers()).unwrap() };
+        let addr = server.local_addr().unwrap();
+        thread::spawn(move || server.run());
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(stream, "POST /day/1/part/1 HTTP/1.1\r\nContent-Length: 100\r\n\r\n1000\n").unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 408 Request Timeout\r\n"), "{}", response);
+        assert!(response.ends_with(r#"{"error":"The request wasn't sent within 0.1 seconds"}"#), "{}", response);
+    }
+
+    #[test]
+    fn requests_that_trickle_in_time_out_too() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (mut stream, _) = listener.accept().unwrap();
+
+        // Each byte comes well within the timeout, but the request takes 3 seconds and never ends
+        thread::spawn(move || {
+            for byte in b"POST /day/1/part/1 HTTP/1.1\r\nX-Slow: ".iter().chain([b'.'].iter().cycle()).take(150) {
+                if client.write_all(&[*byte]).is_err() {
+                    break;
+                }
+
+                thread::sleep(Duration::from_millis(20));
+            }
+        });
+
+        let start = Instant::now();
+        let err = read_request(&mut stream, start + Duration::from_millis(300)).err().expect("the request doesn't end");
+
+        assert!(matches!(err.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut), "{}", err);
+        assert!(start.elapsed() < Duration::from_secs(2), "took {:?}", start.elapsed());
+    }
+
+    #[test]
+    fn handle_works_without_a_connection() {
+        let response = handle(&Answers::default(), "POST", "/day/6/part/1", b"mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+
+        assert_eq!(response.status, 200);
+        assert!(response.body.contains(r#""answer":"7""#), "{}", response.body);
+    }
+}
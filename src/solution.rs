@@ -0,0 +1,76 @@
+use std::{any::Any, sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex}, time::Duration};
+
+use crate::{error::ParseError, input, render::Recording};
+
+/// A single day's puzzle.
+///
+/// The raw input is parsed once and both parts are solved from the parsed value.
+pub trait Solution {
+    type Input: 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input, params: &Params) -> String;
+    fn part2(input: &Self::Input, params: &Params) -> String;
+}
+
+/// Puzzle settings that aren't part of the input file itself
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    /// Whether the input is the example from the puzzle description
+    pub example: bool,
+    /// Day 15: The row to count the covered positions of in part 1
+    pub row: Option<i32>,
+    /// Day 15: The size of the area that the distress beacon is searched for in part 2
+    pub bound: Option<i32>,
+    /// Whether the days that can draw in the terminal should do so while solving
+    pub visualize: bool,
+    /// How long to wait between the frames of a visualization
+    pub delay: Option<Duration>,
+    /// Where the frames of the visualizations are recorded to, for `--record`
+    pub recording: Option<Arc<Mutex<Recording>>>,
+    /// Set when the visualization in the terminal is quit, the run stops after the current part
+    pub quit: Arc<AtomicBool>,
+}
+
+impl Params {
+    pub fn quit(&self) -> bool {
+        self.quit.load(Ordering::Relaxed)
+    }
+
+    /// The delay between frames given on the command line, or the day's own default
+    pub fn delay_or(&self, default: Duration) -> Duration {
+        self.delay.unwrap_or(default)
+    }
+}
+
+/// Object safe version of [`Solution`], used to keep every day in one registry
+/// even though each of them parses into a different type.
+///
+/// The input is [normalized](input::normalize) before it gets to the day's parser.
+pub trait DynSolution: Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    fn part1(&self, parsed: &dyn Any, params: &Params) -> String;
+    fn part2(&self, parsed: &dyn Any, params: &Params) -> String;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        let parsed = <S as Solution>::parse(&input::normalize(input))?;
+
+        Ok(Box::new(parsed))
+    }
+
+    fn part1(&self, parsed: &dyn Any, params: &Params) -> String {
+        <S as Solution>::part1(downcast::<S>(parsed), params)
+    }
+
+    fn part2(&self, parsed: &dyn Any, params: &Params) -> String {
+        <S as Solution>::part2(downcast::<S>(parsed), params)
+    }
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Input {
+    parsed
+        .downcast_ref::<S::Input>()
+        .expect("Parsed input belongs to a different day")
+}
@@ -0,0 +1,281 @@
+use std::{fmt, fs::{self, OpenOptions}, io::{ErrorKind, Write}, path::Path, time::{SystemTime, UNIX_EPOCH}};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{self, Config};
+
+/// What the site said about a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint of which way
+    Wrong,
+    /// The answer wasn't checked because the last wrong one was too recent
+    TooRecent,
+    /// The part is already solved, or part 2 isn't unlocked yet
+    WrongLevel,
+    /// The page didn't look like any of the above
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict from the page that the site answers a submission with
+    pub fn parse(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Verdict::Correct
+        } else if page.contains("answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("answer is too low") {
+            Verdict::TooLow
+        } else if page.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if page.contains("You gave an answer too recently") {
+            Verdict::TooRecent
+        } else if page.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Whether the answer that got this verdict shouldn't be submitted again
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::TooRecent => "too recent",
+            Verdict::WrongLevel => "wrong level",
+            Verdict::Unknown => "unknown",
+        };
+
+        f.pad(name)
+    }
+}
+
+/// A submitted answer, a line of the history file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the unix epoch
+    pub time: u64,
+}
+
+/// Every answer that was submitted so far, kept as a json object per line
+#[derive(Debug, Default)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// A missing file is an empty history
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(History::default()),
+            Err(err) => return Err(format!("Failed to read {}: {}", path.display(), err)),
+        };
+
+        let attempts = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| ! line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line).map_err(|err| format!("Failed to parse line {} of {}: {}", i + 1, path.display(), err))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(History { attempts })
+    }
+
+    pub fn append(path: &Path, attempt: &Attempt) -> Result<(), String> {
+        if let Some(dir) = path.parent().filter(|dir| ! dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).map_err(|err| format!("Failed to create {}: {}", dir.display(), err))?;
+        }
+
+        let line = serde_json::to_string(attempt).expect("Attempts can always be serialized");
+
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| writeln!(file, "{}", line))
+            .map_err(|err| format!("Failed to write {}: {}", path.display(), err))
+    }
+
+    /// Fails when the answer is already known to be wrong, or to be beyond an answer that was too high or too low
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Result<(), String> {
+        let attempts: Vec<&Attempt> = self.attempts.iter().filter(|a| a.day == day && a.part == part).collect();
+
+        if let Some(correct) = attempts.iter().find(|a| a.verdict == Verdict::Correct) {
+            return Err(format!("Day {} part {} was already solved with {}", day, part, correct.answer));
+        }
+
+        if let Some(wrong) = attempts.iter().find(|a| a.answer == answer && a.verdict.is_wrong()) {
+            return Err(format!("{} was already submitted for day {} part {} and was {}", answer, day, part, wrong.verdict));
+        }
+
+        let Ok(value) = answer.parse::<i64>() else {
+            return Ok(());
+        };
+
+        let bound = |verdict: Verdict| attempts.iter()
+            .filter(move |a| a.verdict == verdict)
+            .filter_map(|a| a.answer.parse::<i64>().ok());
+
+        if let Some(high) = bound(Verdict::TooHigh).filter(|&high| value >= high).min() {
+            return Err(format!("{} is too high, {} already was", answer, high));
+        }
+
+        if let Some(low) = bound(Verdict::TooLow).filter(|&low| value <= low).max() {
+            return Err(format!("{} is too low, {} already was", answer, low));
+        }
+
+        Ok(())
+    }
+}
+
+/// What the site answered a submission with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    pub verdict: Verdict,
+    /// The text of the page's message, eg: how long to wait before the next answer
+    pub message: String,
+}
+
+/// Posts the answer of a part after checking it against the history, every answer that gets posted is added to it
+pub fn submit(config: &Config, day: u8, part: u8, answer: &str) -> Result<Outcome, String> {
+    History::load(&config.history)?.check(day, part, answer)?;
+
+    let page = post(config, day, part, answer)?;
+    let outcome = Outcome { verdict: Verdict::parse(&page), message: message(&page) };
+
+    let time = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.as_secs());
+    let attempt = Attempt { day, part, answer: String::from(answer), verdict: outcome.verdict, time };
+
+    History::append(&config.history, &attempt)?;
+    Ok(outcome)
+}
+
+fn post(config: &Config, day: u8, part: u8, answer: &str) -> Result<String, String> {
+    let url = format!("{}/answer", config.day_url(day));
+
+    config::agent()
+        .post(&url)
+        .set("Cookie", &config.cookie()?)
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        .map_err(|err| format!("Failed to submit to {}: {}", url, err))?
+        .into_string()
+        .map_err(|err| format!("Failed to read {}: {}", url, err))
+}
+
+/// The text of the `<article>` of a page without its tags, or nothing if it doesn't have one
+fn message(page: &str) -> String {
+    let Some(article) = page.split_once("<article").and_then(|(_, rest)| rest.split_once("</article>")) else {
+        return String::new();
+    };
+
+    let mut text = String::new();
+    let mut in_tag = true;
+
+    for ch in article.0.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            ch if ! in_tag => text.push(ch),
+            _ => {},
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::test_support::serve;
+
+    use super::*;
+
+    const TOO_HIGH: &str = "<main>\n<article><p>That's not the right answer; your answer is too high.  \
+        If you're stuck, make sure you're using the full input data. <a href=\"/2022/day/1\">[Return to Day 1]</a></p></article>\n</main>";
+
+    fn attempt(answer: &str, verdict: Verdict) -> Attempt {
+        Attempt { day: 1, part: 1, answer: String::from(answer), verdict, time: 0 }
+    }
+
+    fn config(name: &str, base_url: String) -> Config {
+        let dir = std::env::temp_dir().join(format!("aoc-submit-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        Config { session: Some(String::from("abc")), base_url, cache_dir: dir.clone(), history: dir.join("history.jsonl") }
+    }
+
+    #[test]
+    fn verdicts_are_read_from_the_page() {
+        assert_eq!(Verdict::parse(TOO_HIGH), Verdict::TooHigh);
+        assert_eq!(Verdict::parse("That's not the right answer; your answer is too low."), Verdict::TooLow);
+        assert_eq!(Verdict::parse("That's not the right answer.  If you're stuck..."), Verdict::Wrong);
+        assert_eq!(Verdict::parse("That's the right answer!  You are one gold star closer"), Verdict::Correct);
+        assert_eq!(Verdict::parse("You gave an answer too recently; you have to wait"), Verdict::TooRecent);
+        assert_eq!(Verdict::parse("You don't seem to be solving the right level."), Verdict::WrongLevel);
+        assert_eq!(Verdict::parse("<html></html>"), Verdict::Unknown);
+
+        assert!(message(TOO_HIGH).starts_with("That's not the right answer; your answer is too high. If you're stuck"));
+        assert!(message(TOO_HIGH).ends_with("[Return to Day 1]"));
+    }
+
+    #[test]
+    fn known_wrong_answers_are_refused() {
+        let history = History { attempts: vec![attempt("abc", Verdict::Wrong), attempt("100", Verdict::TooHigh), attempt("40", Verdict::TooLow)] };
+
+        assert!(history.check(1, 1, "abc").unwrap_err().contains("was wrong"));
+        assert!(history.check(1, 1, "100").unwrap_err().contains("was too high"));
+        assert_eq!(history.check(1, 1, "120"), Err(String::from("120 is too high, 100 already was")));
+        assert_eq!(history.check(1, 1, "12"), Err(String::from("12 is too low, 40 already was")));
+
+        assert_eq!(history.check(1, 1, "99"), Ok(()));
+        assert_eq!(history.check(1, 1, "abd"), Ok(()));
+        assert_eq!(history.check(1, 2, "100"), Ok(()));
+
+        let history = History { attempts: vec![attempt("41", Verdict::TooRecent), attempt("42", Verdict::Correct)] };
+        assert_eq!(history.check(1, 1, "41"), Err(String::from("Day 1 part 1 was already solved with 42")));
+    }
+
+    #[test]
+    fn submissions_are_added_to_the_history() {
+        let (url, requests) = serve(200, TOO_HIGH);
+        let config = config("history", url);
+
+        let outcome = submit(&config, 1, 1, "100").unwrap();
+        assert_eq!(outcome.verdict, Verdict::TooHigh);
+        assert!(submit(&config, 1, 1, "200").unwrap_err().contains("too high"));
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("POST /2022/day/1/answer HTTP/1.1"));
+        assert!(requests[0].contains("\nCookie: session=abc"));
+        assert!(requests[0].ends_with("\n\nlevel=1&answer=100"));
+
+        let history = History::load(&config.history).unwrap();
+        assert_eq!(history.attempts.len(), 1);
+        assert_eq!(history.attempts[0].answer, "100");
+        assert_eq!(history.attempts[0].verdict, Verdict::TooHigh);
+
+        assert!(History::load(&PathBuf::from("missing.jsonl")).unwrap().attempts.is_empty());
+    }
+}
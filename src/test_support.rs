@@ -0,0 +1,33 @@
+//! Helpers that the tests of several modules share
+
+use std::{io::{BufRead, BufReader, Read, Write}, net::TcpListener, sync::{Arc, Mutex}, thread};
+
+/// An http server on a free port that answers every request with `status` and `body`,
+/// returns its url and every request that it got, their head and body separated by an empty line
+pub fn serve(status: u16, body: &'static str) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let received = Arc::clone(&requests);
+
+    thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(&stream);
+            let head: Vec<String> = reader.by_ref().lines()
+                .map_while(Result::ok)
+                .take_while(|line| ! line.is_empty())
+                .collect();
+
+            let length = head.iter()
+                .find_map(|line| line.to_lowercase().strip_prefix("content-length: ").and_then(|n| n.parse().ok()))
+                .unwrap_or(0);
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+
+            received.lock().unwrap().push(format!("{}\n\n{}", head.join("\n"), String::from_utf8_lossy(&content)));
+            write!(stream, "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+        }
+    });
+
+    (url, requests)
+}
@@ -0,0 +1,116 @@
+use std::fmt;
+
+use crate::{answers::{self, Answers}, days, input::InputSource, solution::Params};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// There's no recorded answer to compare against
+    Missing,
+    /// The input couldn't be read or parsed
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+            Status::Error => "error",
+        };
+
+        // `pad` so that the widths of the table apply
+        f.pad(name)
+    }
+}
+
+/// The outcome of checking one part of a day against one of its input files
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub file_name: &'static str,
+    /// `None` when the day couldn't run at all
+    pub part: Option<u8>,
+    pub status: Status,
+    pub details: String,
+}
+
+/// Runs both parts of a day against its `input` or `exampleinput` and compares them with the recorded answers
+pub fn verify_day(answers: &Answers, day: u8, source: &InputSource) -> Vec<Check> {
+    let file_name = source.file_name().expect("Only the inputs of the days can be verified");
+    let check = |part: Option<u8>, status: Status, details: String| Check { day, file_name, part, status, details };
+
+    let Some(solution) = days::get(day) else {
+        return vec![check(None, Status::Error, format!("Day {} is not implemented", day))];
+    };
+
+    let parsed = source
+        .read(day)
+        .and_then(|contents| {
+            solution.parse(&contents).map_err(|err| err.diagnostic(&source.describe(day), &contents))
+        });
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(err) => return vec![check(None, Status::Error, err)],
+    };
+
+    let params = Params { example: source.is_example(), ..Params::default() };
+    let actual = [
+        solution.part1(parsed.as_ref(), &params),
+        solution.part2(parsed.as_ref(), &params),
+    ];
+
+    (1..=2)
+        .zip(actual)
+        .map(|(part, actual)| match answers.get(day, file_name, part) {
+            Some(expected) if answers::matches(expected, &actual) => check(Some(part), Status::Pass, String::new()),
+            Some(expected) => check(Some(part), Status::Fail, format!("expected {:?}, got {:?}", expected.trim(), actual.trim())),
+            None => check(Some(part), Status::Missing, format!("got {:?}", actual.trim())),
+        })
+        .collect()
+}
+
+/// Prints the checks as a table followed by a summary, fails if any of them failed or couldn't run
+pub fn report(checks: &[Check]) -> Result<(), String> {
+    println!("{:>3}  {:<12}  {:>4}  {:<7}  Details", "Day", "Input", "Part", "Result");
+
+    for check in checks {
+        // Errors can span multiple lines, so they're printed under the row instead of next to it
+        let (details, below) = match check.status {
+            Status::Error => ("", check.details.as_str()),
+            _ => (check.details.as_str(), ""),
+        };
+
+        let row = format!(
+            "{:>3}  {:<12}  {:>4}  {:<7}  {}",
+            check.day,
+            check.file_name,
+            check.part.map_or(String::from("-"), |part| part.to_string()),
+            check.status,
+            details,
+        );
+
+        println!("{}", row.trim_end());
+
+        if ! below.is_empty() {
+            println!("{}", below);
+        }
+    }
+
+    let count = |status: Status| checks.iter().filter(|c| c.status == status).count();
+    let (failed, errors) = (count(Status::Fail), count(Status::Error));
+
+    println!(
+        "\n{} passed, {} failed, {} missing, {} errors",
+        count(Status::Pass), failed, count(Status::Missing), errors,
+    );
+
+    if failed + errors > 0 {
+        return Err(String::from("Some of the answers don't match the recorded ones or couldn't be computed"));
+    }
+
+    Ok(())
+}
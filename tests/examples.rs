@@ -0,0 +1,32 @@
+//! Runs every day against its `exampleinput` and compares the answers with `answers.toml`
+
+use aoc2022::{answers::{self, Answers}, days, input::InputSource, verify::{self, Status}};
+
+fn answers() -> Answers {
+    Answers::load(answers::DEFAULT_PATH.as_ref()).unwrap()
+}
+
+#[test]
+fn examples_match_the_recorded_answers() {
+    let answers = answers();
+
+    // The parts of the days that aren't solved yet don't have an answer to compare against
+    let checks: Vec<verify::Check> = days::DAYS
+        .iter()
+        .flat_map(|&(day, _)| verify::verify_day(&answers, day, &InputSource::Example))
+        .filter(|check| check.status != Status::Missing)
+        .collect();
+
+    for check in &checks {
+        assert_eq!(check.status, Status::Pass, "day {} part {:?}: {}", check.day, check.part, check.details);
+    }
+}
+
+#[test]
+fn days_are_solved_one_part_after_the_other() {
+    let answers = answers();
+
+    for &(day, _) in days::DAYS {
+        assert!(answers.is_solved(day, 1) || ! answers.is_solved(day, 2), "day {} has an answer for part 2 but not part 1", day);
+    }
+}
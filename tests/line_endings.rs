@@ -0,0 +1,62 @@
+//! Every day has to solve its example the same way however the file was saved
+
+use aoc2022::{days, solution::Params};
+
+// Days without recorded answers yet, which are only parsed
+const UNSOLVED_DAYS: [u8; 1] = [19];
+
+fn example(day: u8) -> String {
+    std::fs::read_to_string(format!("{}/exampleinput", days::dir_name(day))).unwrap()
+}
+
+/// The answers of both parts, or the parse error
+fn solve(day: u8, input: &str) -> Result<[String; 2], String> {
+    let solution = days::get(day).unwrap();
+    let parsed = solution.parse(input).map_err(|err| err.diagnostic("exampleinput", input))?;
+
+    if UNSOLVED_DAYS.contains(&day) {
+        return Ok([String::new(), String::new()]);
+    }
+
+    let params = Params { example: true, ..Params::default() };
+
+    Ok([solution.part1(parsed.as_ref(), &params), solution.part2(parsed.as_ref(), &params)])
+}
+
+fn assert_same_answers(variant: &str, change: impl Fn(&str) -> String) {
+    for &(day, _) in days::DAYS {
+        let example = example(day);
+
+        assert_eq!(solve(day, &change(&example)), solve(day, &example), "day {} with {}", day, variant);
+    }
+}
+
+#[test]
+fn crlf_line_endings() {
+    assert_same_answers("crlf", |input| input.replace('\n', "\r\n"));
+}
+
+#[test]
+fn trailing_spaces() {
+    assert_same_answers("trailing spaces", |input| input.replace('\n', "  \n"));
+}
+
+#[test]
+fn trailing_tabs_and_carriage_returns() {
+    assert_same_answers("trailing tabs", |input| input.replace('\n', "\t\r\n"));
+}
+
+#[test]
+fn no_trailing_newline() {
+    assert_same_answers("no trailing newline", |input| input.trim_end().to_string());
+}
+
+#[test]
+fn extra_trailing_newlines() {
+    assert_same_answers("extra trailing newlines", |input| format!("{}\n\n\n", input));
+}
+
+#[test]
+fn extra_trailing_crlf_lines_with_spaces() {
+    assert_same_answers("everything at once", |input| format!("{}\r\n \r\n\r\n", input.replace('\n', " \r\n")));
+}
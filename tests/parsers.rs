@@ -0,0 +1,111 @@
+//! Feeds every day broken versions of its inputs, with the same checks as the fuzz targets in `fuzz/`.
+//! Fuzzing finds a lot more, but needs a nightly toolchain
+
+use std::panic;
+
+use aoc2022::{days, generate::{self, Rng}, parallel};
+use proptest::{collection::vec, prelude::*, sample::Index, test_runner::{Config, TestRunner}};
+
+#[path = "../fuzz/fuzz_targets/common.rs"]
+mod common;
+
+/// Pieces of the inputs of the days, the parsers get further with them than with random characters
+const PIECES: &[&str] = &[
+    "\n", "\n\n", " ", ",", ":", ";", "-", "=", "[", "]", "0", "1", "99999999999999999999", "-1", "é",
+    "<", ">", "->", "$ cd ", "..", "/", "move", "from", "to", "addx", "noop", "old", "*", "+", "S", "E", "AA",
+    "2147483647", "-2147483648", "4294967295", "9223372036854775807", "18446744073709551615",
+];
+
+/// The edges of the integer types, which the parsers either reject or the parts have to get through
+const EXTREMES: &[&str] = &["2147483647", "-2147483648", "4294967295", "9223372036854775807", "18446744073709551615"];
+
+/// Removes, repeats or inserts a few pieces of `input`
+fn mutations(input: String) -> impl Strategy<Value = String> {
+    let edit = (any::<Index>(), 0..20usize, prop_oneof![prop::sample::select(PIECES).prop_map(String::from), ".{0,3}"]);
+
+    vec(edit, 1..=4).prop_map(move |edits| {
+        let mut chars: Vec<char> = input.chars().collect();
+
+        for (at, removed, inserted) in edits {
+            let at = at.index(chars.len() + 1);
+            let removed = removed.min(chars.len() - at);
+
+            chars.splice(at..(at + removed), inserted.chars());
+        }
+
+        chars.into_iter().collect()
+    })
+}
+
+fn check_every_day(inputs: impl Fn(u8) -> BoxedStrategy<String>) {
+    for &(day, _) in days::DAYS {
+        let mut runner = TestRunner::new(Config { cases: 500, failure_persistence: None, ..Config::default() });
+
+        runner
+            .run(&inputs(day), |input| {
+                common::check(day, &input);
+                Ok(())
+            })
+            .unwrap_or_else(|err| panic!("day {}: {}", day, err));
+    }
+}
+
+#[test]
+fn broken_examples_never_panic() {
+    check_every_day(|day| {
+        let example = std::fs::read_to_string(format!("{}/exampleinput", days::dir_name(day))).unwrap();
+
+        mutations(example).boxed()
+    });
+}
+
+#[test]
+fn broken_generated_inputs_never_panic() {
+    check_every_day(|day| {
+        let generated = (generate::get(day).unwrap().generate)(&mut Rng::new(day as u64), 10);
+
+        mutations(generated).boxed()
+    });
+}
+
+#[test]
+fn extreme_numbers_in_the_examples_never_panic() {
+    let mut panics = vec![];
+
+    for &(day, _) in days::DAYS {
+        let example = std::fs::read_to_string(format!("{}/exampleinput", days::dir_name(day))).unwrap();
+        let mut numbers = vec![];
+        let mut start = None;
+
+        for (i, c) in example.char_indices().chain([(example.len(), ' ')]) {
+            match (c.is_ascii_digit(), start) {
+                (true, None) => start = Some(i),
+                (false, Some(from)) => {
+                    numbers.push(from..i);
+                    start = None;
+                },
+                _ => (),
+            }
+        }
+
+        // One number at a time, so that the rest of the input still parses
+        for number in numbers {
+            let negative = example[..number.start].ends_with('-');
+
+            for extreme in EXTREMES.iter().filter(|extreme| ! (negative && extreme.starts_with('-'))) {
+                let input = format!("{}{}{}", &example[..number.start], extreme, &example[number.end..]);
+
+                if let Err(payload) = panic::catch_unwind(|| common::check(day, &input)) {
+                    panics.push(format!("day {}: {} on {:?}", day, parallel::panic_message(payload.as_ref()), input));
+                }
+            }
+        }
+    }
+
+    assert!(panics.is_empty(), "{}", panics.join("\n"));
+}
+
+#[test]
+fn random_text_never_panics() {
+    check_every_day(|_| ".{0,40}".boxed());
+}